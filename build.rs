@@ -0,0 +1,395 @@
+//! Generates `$OUT_DIR/openapi_operations.rs` from `openapi/openapi.json`.
+//!
+//! Emits:
+//! - `OPENAPI_DEFAULT_SERVER_URL` and the flat `OPENAPI_OPERATIONS` catalog
+//!   consumed by `openapi_client::call_operation`/`call_operation_as` and by
+//!   `IriClient::tag`/`operations_by_tag` (string-keyed, dynamic lookup).
+//! - One accessor struct per `OpenAPI` tag (for example `tags::ProjectsOperations`
+//!   and its `tags::BlockingProjectsOperations` counterpart), each with a
+//!   named method per operation in that tag plus a `*_as::<T>` twin for a
+//!   typed response, mirroring `call_operation`/`call_operation_as`.
+//! - A generated accessor method per tag on `IriClient`/`BlockingIriClient`
+//!   (`client.projects()`), so a typo'd operation id or a missing path
+//!   parameter is a compile error instead of a runtime one.
+//!
+//! Path and query parameters are emitted as `&str`/`Option<&str>`: `OpenAPI`
+//! parameter schemas aren't modelled into richer Rust scalar types here, and
+//! request/response bodies stay `serde_json::Value` (no schema-to-struct
+//! generation exists in this crate), consistent with how `call_operation`/
+//! `call_operation_as` already treat both.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo"));
+    let spec_path = manifest_dir.join("openapi").join("openapi.json");
+    println!("cargo:rerun-if-changed={}", spec_path.display());
+
+    let spec_text = fs::read_to_string(&spec_path)
+        .unwrap_or_else(|err| panic!("failed to read OpenAPI spec at {}: {err}", spec_path.display()));
+    let spec: Value = serde_json::from_str(&spec_text)
+        .unwrap_or_else(|err| panic!("failed to parse OpenAPI spec at {}: {err}", spec_path.display()));
+
+    let default_server_url = spec["servers"][0]["url"].as_str().unwrap_or_default().to_owned();
+    let operations = collect_operations(&spec);
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    fs::write(
+        out_dir.join("openapi_operations.rs"),
+        render(&default_server_url, &operations),
+    )
+    .expect("failed to write generated openapi_operations.rs");
+}
+
+/// One path/query parameter of an operation.
+struct Param {
+    /// Original `OpenAPI` parameter name (used on the wire).
+    wire_name: String,
+    /// Sanitized Rust identifier for this parameter.
+    ident: String,
+    required: bool,
+}
+
+/// One `OpenAPI` operation, flattened out of `paths`.
+struct Operation {
+    operation_id: String,
+    method: String,
+    path_template: String,
+    path_params: Vec<Param>,
+    query_params: Vec<Param>,
+    tags: Vec<String>,
+    has_body: bool,
+}
+
+const HTTP_METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
+fn collect_operations(spec: &Value) -> Vec<Operation> {
+    let mut operations = Vec::new();
+    let Some(paths) = spec.get("paths").and_then(Value::as_object) else {
+        return operations;
+    };
+
+    for (path_template, path_item) in paths {
+        let Some(path_item) = path_item.as_object() else {
+            continue;
+        };
+        for method in HTTP_METHODS {
+            let Some(op) = path_item.get(*method) else {
+                continue;
+            };
+            let Some(operation_id) = op.get("operationId").and_then(Value::as_str) else {
+                // Operations without an operationId can't be addressed by name; skip.
+                continue;
+            };
+
+            let mut path_params = Vec::new();
+            let mut query_params = Vec::new();
+            for param in op.get("parameters").and_then(Value::as_array).into_iter().flatten() {
+                let Some(name) = param.get("name").and_then(Value::as_str) else {
+                    continue;
+                };
+                let required = param.get("required").and_then(Value::as_bool).unwrap_or(false);
+                let param = Param {
+                    wire_name: name.to_owned(),
+                    ident: sanitize_ident(&to_snake_case(name)),
+                    required,
+                };
+                match param_location(param_in(spec, op, name)) {
+                    Some(Location::Path) => path_params.push(param),
+                    Some(Location::Query) => query_params.push(param),
+                    None => {}
+                }
+            }
+
+            operations.push(Operation {
+                operation_id: operation_id.to_owned(),
+                method: method.to_ascii_uppercase(),
+                path_template: path_template.clone(),
+                path_params,
+                query_params,
+                tags: op
+                    .get("tags")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .map(str::to_owned)
+                    .collect(),
+                has_body: matches!(*method, "post" | "put" | "patch"),
+            });
+        }
+    }
+
+    operations
+}
+
+enum Location {
+    Path,
+    Query,
+}
+
+fn param_location(raw: Option<&str>) -> Option<Location> {
+    match raw {
+        Some("path") => Some(Location::Path),
+        Some("query") => Some(Location::Query),
+        _ => None,
+    }
+}
+
+fn param_in<'a>(_spec: &Value, op: &'a Value, name: &str) -> Option<&'a str> {
+    op.get("parameters")?
+        .as_array()?
+        .iter()
+        .find(|param| param.get("name").and_then(Value::as_str) == Some(name))?
+        .get("in")?
+        .as_str()
+}
+
+/// Converts an `OpenAPI` identifier (`camelCase`, `kebab-case`, or mixed) to `snake_case`.
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for ch in name.chars() {
+        if ch == '-' || ch == '.' {
+            out.push('_');
+        } else if ch.is_ascii_uppercase() {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(ch.to_ascii_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// Converts a tag or identifier to `PascalCase` for use as a Rust type name.
+fn to_pascal_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut capitalize_next = true;
+    for ch in name.chars() {
+        if ch == '-' || ch == '_' || ch == '.' || ch == ' ' {
+            capitalize_next = true;
+            continue;
+        }
+        if capitalize_next {
+            out.extend(ch.to_uppercase());
+            capitalize_next = false;
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// Guards against generating an identifier that collides with a Rust keyword.
+fn sanitize_ident(ident: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for", "if", "impl",
+        "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "static", "struct",
+        "super", "trait", "true", "type", "unsafe", "use", "where", "while", "async", "await", "dyn",
+    ];
+    if ident.is_empty() {
+        "param".to_owned()
+    } else if KEYWORDS.contains(&ident) {
+        format!("{ident}_")
+    } else if ident.chars().next().is_some_and(|ch| ch.is_ascii_digit()) {
+        format!("_{ident}")
+    } else {
+        ident.to_owned()
+    }
+}
+
+fn render(default_server_url: &str, operations: &[Operation]) -> String {
+    let mut out = String::new();
+
+    writeln!(out, "pub const OPENAPI_DEFAULT_SERVER_URL: &str = {default_server_url:?};").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "pub static OPENAPI_OPERATIONS: &[OperationDefinition] = &[").unwrap();
+    for operation in operations {
+        let path_param_names: Vec<&str> = operation.path_params.iter().map(|p| p.wire_name.as_str()).collect();
+        writeln!(out, "    OperationDefinition {{").unwrap();
+        writeln!(out, "        operation_id: {:?},", operation.operation_id).unwrap();
+        writeln!(out, "        method: {:?},", operation.method).unwrap();
+        writeln!(out, "        path_template: {:?},", operation.path_template).unwrap();
+        writeln!(out, "        path_params: &{path_param_names:?},").unwrap();
+        writeln!(out, "        tags: &{:?},", operation.tags).unwrap();
+        writeln!(out, "    }},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+
+    let mut by_tag: BTreeMap<&str, Vec<&Operation>> = BTreeMap::new();
+    for operation in operations {
+        for tag in &operation.tags {
+            by_tag.entry(tag.as_str()).or_default().push(operation);
+        }
+    }
+
+    writeln!(out, "/// Compile-time-checked, per-tag operation accessors.").unwrap();
+    writeln!(out, "///").unwrap();
+    writeln!(
+        out,
+        "/// Generated from the `tags` carried by each `OpenAPI` operation; see"
+    )
+    .unwrap();
+    writeln!(out, "/// [`crate::IriClient::tag`] for the dynamic, string-keyed equivalent.").unwrap();
+    writeln!(out, "pub mod tags {{").unwrap();
+    writeln!(out, "    #![allow(clippy::too_many_arguments)]").unwrap();
+    for (tag, ops) in &by_tag {
+        render_tag_struct(&mut out, tag, ops, false);
+        render_tag_struct(&mut out, tag, ops, true);
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "impl IriClient {{").unwrap();
+    for tag in by_tag.keys() {
+        let accessor = sanitize_ident(&to_snake_case(tag));
+        let struct_name = to_pascal_case(tag);
+        writeln!(out, "    /// Returns the generated accessor for operations tagged `{tag}`.").unwrap();
+        writeln!(out, "    pub fn {accessor}(&self) -> tags::{struct_name}Operations<'_> {{").unwrap();
+        writeln!(out, "        tags::{struct_name}Operations {{ client: self }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "impl BlockingIriClient {{").unwrap();
+    for tag in by_tag.keys() {
+        let accessor = sanitize_ident(&to_snake_case(tag));
+        let struct_name = to_pascal_case(tag);
+        writeln!(out, "    /// Returns the generated accessor for operations tagged `{tag}`.").unwrap();
+        writeln!(out, "    pub fn {accessor}(&self) -> tags::Blocking{struct_name}Operations<'_> {{").unwrap();
+        writeln!(out, "        tags::Blocking{struct_name}Operations {{ client: self }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    out
+}
+
+fn render_tag_struct(out: &mut String, tag: &str, ops: &[&Operation], blocking: bool) {
+    let struct_name = if blocking {
+        format!("Blocking{}Operations", to_pascal_case(tag))
+    } else {
+        format!("{}Operations", to_pascal_case(tag))
+    };
+    let client_type = if blocking { "BlockingIriClient" } else { "IriClient" };
+    let asyncness = if blocking { "" } else { "async " };
+    let await_suffix = if blocking { "" } else { ".await" };
+
+    writeln!(out, "    /// Generated accessor for operations tagged `{tag}`.").unwrap();
+    writeln!(
+        out,
+        "    pub struct {struct_name}<'a> {{ pub(crate) client: &'a crate::{client_type} }}"
+    )
+    .unwrap();
+    writeln!(out, "    impl<'a> {struct_name}<'a> {{").unwrap();
+    for op in ops {
+        render_operation_methods(out, op, asyncness, await_suffix);
+    }
+    writeln!(out, "    }}").unwrap();
+}
+
+fn render_operation_methods(out: &mut String, op: &Operation, asyncness: &str, await_suffix: &str) {
+    let method_name = sanitize_ident(&to_snake_case(&op.operation_id));
+
+    let mut params = String::new();
+    for param in &op.path_params {
+        write!(params, "{}: &str, ", param.ident).unwrap();
+    }
+    for param in &op.query_params {
+        if param.required {
+            write!(params, "{}: &str, ", param.ident).unwrap();
+        } else {
+            write!(params, "{}: Option<&str>, ", param.ident).unwrap();
+        }
+    }
+    if op.has_body {
+        params.push_str("body: Option<serde_json::Value>, ");
+    }
+
+    let path_params_expr = render_pairs(&op.path_params);
+    let query_setup = render_query_setup(&op.query_params);
+    let body_expr = if op.has_body { "body" } else { "None" };
+
+    writeln!(
+        out,
+        "        /// `{} {}` (`operationId` `{}`).",
+        op.method, op.path_template, op.operation_id
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "        pub {asyncness}fn {method_name}(&self, {params}) -> Result<serde_json::Value, crate::ClientError> {{"
+    )
+    .unwrap();
+    out.push_str(&query_setup);
+    writeln!(
+        out,
+        "            self.client.call_operation({:?}, &{path_params_expr}, &query, {body_expr}){await_suffix}",
+        op.operation_id
+    )
+    .unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "        /// Like [`Self::{method_name}`], deserializing the response into `T`."
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "        pub {asyncness}fn {method_name}_as<T>(&self, {params}) -> Result<T, crate::ClientError>"
+    )
+    .unwrap();
+    writeln!(out, "        where").unwrap();
+    writeln!(out, "            T: serde::de::DeserializeOwned + Default,").unwrap();
+    writeln!(out, "        {{").unwrap();
+    out.push_str(&query_setup);
+    writeln!(
+        out,
+        "            self.client.call_operation_as({:?}, &{path_params_expr}, &query, {body_expr}){await_suffix}",
+        op.operation_id
+    )
+    .unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out).unwrap();
+}
+
+fn render_pairs(params: &[Param]) -> String {
+    let mut out = String::from("[");
+    for param in params {
+        write!(out, "({:?}, {}), ", param.wire_name, param.ident).unwrap();
+    }
+    out.push(']');
+    out
+}
+
+fn render_query_setup(params: &[Param]) -> String {
+    let mut out = String::new();
+    let binding = if params.is_empty() { "query" } else { "mut query" };
+    writeln!(out, "            let {binding}: Vec<(&str, &str)> = Vec::new();").unwrap();
+    for param in params {
+        if param.required {
+            writeln!(out, "            query.push(({:?}, {}));", param.wire_name, param.ident).unwrap();
+        } else {
+            writeln!(
+                out,
+                "            if let Some(value) = {} {{ query.push(({:?}, value)); }}",
+                param.ident, param.wire_name
+            )
+            .unwrap();
+        }
+    }
+    out
+}
@@ -0,0 +1,436 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::ClientError;
+
+/// Refresh the cached token this long before it actually expires.
+const REFRESH_SKEW: Duration = Duration::from_secs(60);
+
+/// Clamps [`REFRESH_SKEW`] to at most half of `lifetime`.
+///
+/// Without this, a token whose `lifetime` is shorter than `REFRESH_SKEW` (e.g.
+/// a 30s-lived token with the default 60s skew) would read as expired the
+/// instant it's created, permanently forcing a re-fetch/error on every single
+/// request instead of only near real expiry.
+fn refresh_skew(lifetime: Duration) -> Duration {
+    REFRESH_SKEW.min(lifetime / 2)
+}
+
+/// OAuth2 client-credentials grant parameters.
+#[derive(Clone)]
+pub(crate) struct OAuth2Config {
+    pub(crate) token_url: String,
+    pub(crate) client_id: String,
+    pub(crate) client_secret: String,
+    pub(crate) scopes: Vec<String>,
+}
+
+impl fmt::Debug for OAuth2Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2Config")
+            .field("token_url", &self.token_url)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"<redacted>")
+            .field("scopes", &self.scopes)
+            .finish()
+    }
+}
+
+/// A caller-supplied bearer token attached via `with_authorization_token`.
+///
+/// Unlike [`TokenProvider`]'s OAuth2 client-credentials tokens, this one isn't
+/// obtained from a token endpoint, so there's nothing to re-fetch once it
+/// expires. When a lifetime is known (`with_authorization_token_expiring_in`),
+/// [`StaticToken::is_expired`] lets callers fail loudly instead of silently
+/// sending a token they already know is stale.
+#[derive(Clone)]
+pub(crate) struct StaticToken {
+    pub(crate) value: String,
+    created_at: Instant,
+    expires_in: Option<Duration>,
+}
+
+impl StaticToken {
+    pub(crate) fn new(value: String) -> Self {
+        Self {
+            value,
+            created_at: Instant::now(),
+            expires_in: None,
+        }
+    }
+
+    pub(crate) fn with_expiry(value: String, expires_in: Duration) -> Self {
+        Self {
+            value,
+            created_at: Instant::now(),
+            expires_in: Some(expires_in),
+        }
+    }
+
+    /// Returns `true` once the token is within a refresh skew of (or past) the
+    /// end of its known lifetime. Always `false` when no lifetime was supplied.
+    ///
+    /// The skew is [`REFRESH_SKEW`], clamped (via [`refresh_skew`]) to at most
+    /// half the token's lifetime, so a short-lived token isn't treated as
+    /// expired the moment it's created.
+    pub(crate) fn is_expired(&self) -> bool {
+        match self.expires_in {
+            Some(expires_in) => self.created_at.elapsed() + refresh_skew(expires_in) >= expires_in,
+            None => false,
+        }
+    }
+}
+
+impl fmt::Debug for StaticToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticToken")
+            .field("value", &"<redacted>")
+            .field("expires_in", &self.expires_in)
+            .finish()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    #[allow(dead_code)]
+    token_type: Option<String>,
+    #[serde(default)]
+    expires_in: Option<u64>,
+    #[serde(default)]
+    refresh_token: Option<String>,
+}
+
+#[derive(Clone)]
+struct CachedToken {
+    access_token: String,
+    issued_at: Instant,
+    lifetime: Option<Duration>,
+    refresh_token: Option<String>,
+}
+
+impl fmt::Debug for CachedToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedToken")
+            .field("access_token", &"<redacted>")
+            .field("lifetime", &self.lifetime)
+            .field("refresh_token", &self.refresh_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl CachedToken {
+    /// Returns `true` once the token is within a refresh skew of (or past) the
+    /// end of its known lifetime. Always `false` when no lifetime was supplied.
+    ///
+    /// See [`StaticToken::is_expired`] for why the skew is clamped via
+    /// [`refresh_skew`] instead of always being the full [`REFRESH_SKEW`].
+    fn needs_refresh(&self) -> bool {
+        match self.lifetime {
+            Some(lifetime) => self.issued_at.elapsed() + refresh_skew(lifetime) >= lifetime,
+            None => false,
+        }
+    }
+}
+
+fn form_body(config: &OAuth2Config) -> Vec<(&'static str, String)> {
+    let mut pairs = vec![
+        ("grant_type", "client_credentials".to_owned()),
+        ("client_id", config.client_id.clone()),
+        ("client_secret", config.client_secret.clone()),
+    ];
+    if !config.scopes.is_empty() {
+        pairs.push(("scope", config.scopes.join(" ")));
+    }
+    pairs
+}
+
+/// Builds the token-endpoint form body for an RFC 6749 refresh-token grant.
+fn refresh_form_body(config: &OAuth2Config, refresh_token: &str) -> Vec<(&'static str, String)> {
+    vec![
+        ("grant_type", "refresh_token".to_owned()),
+        ("refresh_token", refresh_token.to_owned()),
+        ("client_id", config.client_id.clone()),
+        ("client_secret", config.client_secret.clone()),
+    ]
+}
+
+fn parse_token_response(
+    payload: &str,
+    status: reqwest::StatusCode,
+    previous_refresh_token: Option<&str>,
+) -> Result<CachedToken, ClientError> {
+    if !status.is_success() {
+        return Err(ClientError::Auth(format!(
+            "token endpoint returned status {status}: {payload}"
+        )));
+    }
+
+    let response: TokenResponse = serde_json::from_str(payload)
+        .map_err(|error| ClientError::Auth(format!("failed to parse token response: {error}")))?;
+
+    Ok(CachedToken {
+        access_token: response.access_token,
+        issued_at: Instant::now(),
+        lifetime: response.expires_in.map(Duration::from_secs),
+        // Some providers omit `refresh_token` on a refresh response, meaning the
+        // original refresh token is still valid and should keep being used.
+        refresh_token: response
+            .refresh_token
+            .or_else(|| previous_refresh_token.map(ToOwned::to_owned)),
+    })
+}
+
+/// Caches and refreshes a bearer token obtained via the OAuth2 client-credentials grant.
+///
+/// Shared between clones of [`crate::ApiClient`] so concurrent requests don't each
+/// trigger their own token fetch.
+#[derive(Debug)]
+pub(crate) struct TokenProvider {
+    config: OAuth2Config,
+    http: reqwest::Client,
+    cached: Mutex<Option<CachedToken>>,
+}
+
+impl TokenProvider {
+    pub(crate) fn new(http: reqwest::Client, config: OAuth2Config) -> Arc<Self> {
+        Arc::new(Self {
+            config,
+            http,
+            cached: Mutex::new(None),
+        })
+    }
+
+    /// Returns a provider with the same OAuth2 config but a different underlying
+    /// HTTP client, dropping any cached token.
+    ///
+    /// Used by `ApiClient::with_http_client`/`with_timeout` so a provider set up
+    /// before those calls doesn't keep issuing token requests through the old
+    /// client, regardless of call order.
+    pub(crate) fn with_http_client(&self, http: reqwest::Client) -> Arc<Self> {
+        Self::new(http, self.config.clone())
+    }
+
+    /// Returns a valid bearer token, fetching or refreshing it if necessary.
+    ///
+    /// Holds the cache mutex across the refresh so concurrent callers don't
+    /// stampede the token endpoint.
+    pub(crate) async fn token(&self) -> Result<String, ClientError> {
+        let mut cached = self.cached.lock().await;
+
+        if cached.as_ref().is_none_or(CachedToken::needs_refresh) {
+            let previous = cached.take();
+            *cached = Some(self.fetch_token(previous.as_ref()).await?);
+        }
+
+        Ok(cached.as_ref().expect("just populated").access_token.clone())
+    }
+
+    /// Fetches a new token, using `previous`'s refresh token (if any) to avoid a
+    /// full client-credentials round trip when the provider supports it.
+    async fn fetch_token(&self, previous: Option<&CachedToken>) -> Result<CachedToken, ClientError> {
+        let previous_refresh_token = previous.and_then(|token| token.refresh_token.as_deref());
+        let form = match previous_refresh_token {
+            Some(refresh_token) => refresh_form_body(&self.config, refresh_token),
+            None => form_body(&self.config),
+        };
+
+        let response = self
+            .http
+            .post(&self.config.token_url)
+            .header(reqwest::header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .form(&form)
+            .send()
+            .await
+            .map_err(|error| ClientError::Auth(format!("token request failed: {error}")))?;
+
+        let status = response.status();
+        let payload = response
+            .text()
+            .await
+            .map_err(|error| ClientError::Auth(format!("failed to read token response: {error}")))?;
+
+        parse_token_response(&payload, status, previous_refresh_token)
+    }
+}
+
+/// Blocking counterpart of [`TokenProvider`].
+#[derive(Debug)]
+pub(crate) struct BlockingTokenProvider {
+    config: OAuth2Config,
+    http: reqwest::blocking::Client,
+    cached: std::sync::Mutex<Option<CachedToken>>,
+}
+
+impl BlockingTokenProvider {
+    pub(crate) fn new(http: reqwest::blocking::Client, config: OAuth2Config) -> Arc<Self> {
+        Arc::new(Self {
+            config,
+            http,
+            cached: std::sync::Mutex::new(None),
+        })
+    }
+
+    /// Returns a provider with the same OAuth2 config but a different underlying
+    /// HTTP client, dropping any cached token.
+    ///
+    /// See [`TokenProvider::with_http_client`] for details.
+    pub(crate) fn with_http_client(&self, http: reqwest::blocking::Client) -> Arc<Self> {
+        Self::new(http, self.config.clone())
+    }
+
+    /// Returns a valid bearer token, fetching or refreshing it if necessary.
+    pub(crate) fn token(&self) -> Result<String, ClientError> {
+        let mut cached = self.cached.lock().expect("token cache mutex poisoned");
+
+        if cached.as_ref().is_none_or(CachedToken::needs_refresh) {
+            let previous = cached.take();
+            *cached = Some(self.fetch_token(previous.as_ref())?);
+        }
+
+        Ok(cached.as_ref().expect("just populated").access_token.clone())
+    }
+
+    /// Fetches a new token, using `previous`'s refresh token (if any) to avoid a
+    /// full client-credentials round trip when the provider supports it.
+    fn fetch_token(&self, previous: Option<&CachedToken>) -> Result<CachedToken, ClientError> {
+        let previous_refresh_token = previous.and_then(|token| token.refresh_token.as_deref());
+        let form = match previous_refresh_token {
+            Some(refresh_token) => refresh_form_body(&self.config, refresh_token),
+            None => form_body(&self.config),
+        };
+
+        let response = self
+            .http
+            .post(&self.config.token_url)
+            .header(reqwest::header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .form(&form)
+            .send()
+            .map_err(|error| ClientError::Auth(format!("token request failed: {error}")))?;
+
+        let status = response.status();
+        let payload = response
+            .text()
+            .map_err(|error| ClientError::Auth(format!("failed to read token response: {error}")))?;
+
+        parse_token_response(&payload, status, previous_refresh_token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{CachedToken, OAuth2Config, StaticToken, form_body, parse_token_response, refresh_skew};
+
+    fn config() -> OAuth2Config {
+        OAuth2Config {
+            token_url: "https://auth.example.com/token".to_owned(),
+            client_id: "client-1".to_owned(),
+            client_secret: "secret".to_owned(),
+            scopes: vec!["read".to_owned(), "write".to_owned()],
+        }
+    }
+
+    #[test]
+    fn form_body_includes_scope_when_present() {
+        let pairs = form_body(&config());
+        assert!(pairs.contains(&("grant_type", "client_credentials".to_owned())));
+        assert!(pairs.contains(&("scope", "read write".to_owned())));
+    }
+
+    #[test]
+    fn form_body_omits_scope_when_empty() {
+        let mut cfg = config();
+        cfg.scopes.clear();
+        let pairs = form_body(&cfg);
+        assert!(!pairs.iter().any(|(key, _)| *key == "scope"));
+    }
+
+    #[test]
+    fn parse_token_response_rejects_error_status() {
+        let error = parse_token_response("{}", reqwest::StatusCode::UNAUTHORIZED, None)
+            .expect_err("should fail");
+        assert!(matches!(error, crate::ClientError::Auth(_)));
+    }
+
+    #[test]
+    fn parse_token_response_computes_expiry() {
+        let token = parse_token_response(
+            r#"{"access_token":"abc","token_type":"Bearer","expires_in":3600}"#,
+            reqwest::StatusCode::OK,
+            None,
+        )
+        .expect("valid response");
+        assert_eq!(token.access_token, "abc");
+        assert!(token.lifetime.is_some());
+    }
+
+    #[test]
+    fn parse_token_response_keeps_previous_refresh_token_when_omitted() {
+        let token = parse_token_response(
+            r#"{"access_token":"abc"}"#,
+            reqwest::StatusCode::OK,
+            Some("old-refresh"),
+        )
+        .expect("valid response");
+        assert_eq!(token.refresh_token.as_deref(), Some("old-refresh"));
+    }
+
+    #[test]
+    fn parse_token_response_prefers_new_refresh_token() {
+        let token = parse_token_response(
+            r#"{"access_token":"abc","refresh_token":"new-refresh"}"#,
+            reqwest::StatusCode::OK,
+            Some("old-refresh"),
+        )
+        .expect("valid response");
+        assert_eq!(token.refresh_token.as_deref(), Some("new-refresh"));
+    }
+
+    #[test]
+    fn static_token_without_expiry_never_expires() {
+        let token = StaticToken::new("abc".to_owned());
+        assert!(!token.is_expired());
+    }
+
+    #[test]
+    fn static_token_is_expired_once_past_its_lifetime() {
+        let token = StaticToken::with_expiry("abc".to_owned(), Duration::from_secs(0));
+        assert!(token.is_expired());
+    }
+
+    #[test]
+    fn static_token_is_not_expired_well_within_its_lifetime() {
+        let token = StaticToken::with_expiry("abc".to_owned(), Duration::from_secs(3600));
+        assert!(!token.is_expired());
+    }
+
+    #[test]
+    fn static_token_with_lifetime_shorter_than_refresh_skew_is_not_immediately_expired() {
+        let token = StaticToken::with_expiry("abc".to_owned(), Duration::from_secs(30));
+        assert!(!token.is_expired());
+    }
+
+    #[test]
+    fn refresh_skew_never_exceeds_half_the_lifetime() {
+        assert_eq!(refresh_skew(Duration::from_secs(30)), Duration::from_secs(15));
+        assert_eq!(refresh_skew(Duration::from_secs(3600)), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn needs_refresh_is_false_for_a_freshly_cached_short_lived_token() {
+        let token = CachedToken {
+            access_token: "abc".to_owned(),
+            issued_at: Instant::now(),
+            lifetime: Some(Duration::from_secs(30)),
+            refresh_token: None,
+        };
+        assert!(!token.needs_refresh());
+    }
+}
@@ -1,18 +1,45 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
 use reqwest::{Method, Url};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 use crate::ClientError;
+use crate::auth::{BlockingTokenProvider, OAuth2Config, StaticToken};
+use crate::middleware::BlockingMiddleware;
+use crate::multipart::MultipartPart;
+use crate::retry::{self, RetryPolicy};
 
 /// Generic blocking JSON REST client.
 ///
 /// This is the synchronous counterpart of [`crate::ApiClient`].
-#[derive(Debug)]
 pub struct BlockingApiClient {
     base_url: Url,
-    authorization_token: Option<String>,
+    authorization_token: Option<StaticToken>,
+    oauth2: Option<Arc<BlockingTokenProvider>>,
+    middleware: Vec<Arc<dyn BlockingMiddleware>>,
+    retry_policy: Option<RetryPolicy>,
     http: reqwest::blocking::Client,
 }
 
+impl fmt::Debug for BlockingApiClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // See `ApiClient`'s `Debug` impl: these two fields hold live credentials,
+        // so only their presence is reported, never their value.
+        f.debug_struct("BlockingApiClient")
+            .field("base_url", &self.base_url)
+            .field("authorization_token", &self.authorization_token.is_some())
+            .field("oauth2", &self.oauth2.is_some())
+            .field("middleware_count", &self.middleware.len())
+            .field("retry_policy", &self.retry_policy)
+            .field("http", &self.http)
+            .finish()
+    }
+}
+
 impl BlockingApiClient {
     /// Creates a new client with the given base URL.
     ///
@@ -25,19 +52,108 @@ impl BlockingApiClient {
         Ok(Self {
             base_url: ensure_trailing_slash(parsed),
             authorization_token: None,
+            oauth2: None,
+            middleware: Vec::new(),
+            retry_policy: None,
             http: reqwest::blocking::Client::new(),
         })
     }
 
     /// Returns a new client with a raw access token attached to all requests.
     ///
-    /// This sets `Authorization: <token>` (without `Bearer ` prefix).
+    /// This sets `Authorization: Bearer <token>`. The token's
+    /// lifetime is unknown, so it is sent until replaced; use
+    /// [`Self::with_authorization_token_expiring_in`] if the caller knows how
+    /// long it's valid for.
     #[must_use]
     pub fn with_authorization_token(mut self, token: impl Into<String>) -> Self {
-        self.authorization_token = Some(token.into());
+        self.authorization_token = Some(StaticToken::new(token.into()));
+        self
+    }
+
+    /// Returns a new client with a raw access token that is known to expire after `lifetime`.
+    ///
+    /// See [`crate::ApiClient::with_authorization_token_expiring_in`] for details.
+    #[must_use]
+    pub fn with_authorization_token_expiring_in(mut self, token: impl Into<String>, lifetime: Duration) -> Self {
+        self.authorization_token = Some(StaticToken::with_expiry(token.into(), lifetime));
+        self
+    }
+
+    /// Returns a new client that authenticates via the OAuth2 client-credentials grant.
+    ///
+    /// See [`crate::ApiClient::with_oauth2_client_credentials`] for details. Takes
+    /// precedence over [`Self::with_authorization_token`] when both are set.
+    #[must_use]
+    pub fn with_oauth2_client_credentials(
+        mut self,
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.oauth2 = Some(BlockingTokenProvider::new(
+            self.http.clone(),
+            OAuth2Config {
+                token_url: token_url.into(),
+                client_id: client_id.into(),
+                client_secret: client_secret.into(),
+                scopes: scopes.into_iter().map(Into::into).collect(),
+            },
+        ));
+        self
+    }
+
+    /// Registers a [`BlockingMiddleware`] that observes or rewrites every request/response.
+    ///
+    /// Middlewares run in registration order.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: impl BlockingMiddleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Returns a new client that retries requests on transient failures under `policy`.
+    ///
+    /// See [`crate::ApiClient::with_retry`] for details.
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Returns a new client backed by a caller-supplied [`reqwest::blocking::Client`].
+    ///
+    /// See [`crate::ApiClient::with_http_client`] for details. Overrides any
+    /// client built by [`Self::with_timeout`].
+    #[must_use]
+    pub fn with_http_client(mut self, http: reqwest::blocking::Client) -> Self {
+        self.retarget_oauth2_http_client(http.clone());
+        self.http = http;
+        self
+    }
+
+    /// Returns a new client with the given per-request timeout.
+    ///
+    /// See [`crate::ApiClient::with_timeout`] for details.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        let http = reqwest::blocking::Client::builder()
+            .timeout(timeout)
+            .build()
+            .expect("reqwest client builder with only a timeout set should not fail");
+        self.retarget_oauth2_http_client(http.clone());
+        self.http = http;
         self
     }
 
+    /// Rebuilds the OAuth2 token provider (if any) against `http`.
+    fn retarget_oauth2_http_client(&mut self, http: reqwest::blocking::Client) {
+        if let Some(oauth2) = &self.oauth2 {
+            self.oauth2 = Some(oauth2.with_http_client(http));
+        }
+    }
+
     /// Sends a `GET` request and parses the response as JSON.
     pub fn get_json(&self, path: &str) -> Result<Value, ClientError> {
         self.request_json(Method::GET, path, None)
@@ -52,6 +168,54 @@ impl BlockingApiClient {
         self.request_json_with_query(Method::GET, path, query, None)
     }
 
+    /// Sends a `GET` request and deserializes the response into `T`.
+    ///
+    /// An empty successful body deserializes to `T::default()`. Use
+    /// [`Self::request_as_with_query`] for query parameters, or
+    /// [`Self::request_as_optional_with_query`] when `T` has no sensible default.
+    pub fn get_as<T: DeserializeOwned + Default>(&self, path: &str) -> Result<T, ClientError> {
+        self.request_as(Method::GET, path, None)
+    }
+
+    /// Sends a request and deserializes the response into `T`.
+    ///
+    /// An empty successful body deserializes to `T::default()`.
+    pub fn request_as<T: DeserializeOwned + Default>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Value>,
+    ) -> Result<T, ClientError> {
+        self.request_as_with_query(method, path, &[], body)
+    }
+
+    /// Sends a request with query parameters and deserializes the response into `T`.
+    ///
+    /// An empty successful body deserializes to `T::default()`.
+    pub fn request_as_with_query<T: DeserializeOwned + Default>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<T, ClientError> {
+        deserialize_as(self.send(method, path, query, body)?)
+    }
+
+    /// Sends a request with query parameters and deserializes the response into `T`.
+    ///
+    /// Returns `None` for a successful response with an empty body, distinguishing
+    /// "no content" from a parsed value without requiring `T: Default`.
+    pub fn request_as_optional_with_query<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<T>, ClientError> {
+        deserialize_as_optional(self.send(method, path, query, body)?)
+    }
+
     /// Sends a request and parses the response as JSON.
     ///
     /// Use [`Self::request_json_with_query`] when query parameters are needed.
@@ -74,35 +238,259 @@ impl BlockingApiClient {
         query: &[(&str, &str)],
         body: Option<Value>,
     ) -> Result<Value, ClientError> {
-        let url = self.build_url(path)?;
+        match self.send(method, path, query, body)? {
+            Some(payload) => Ok(serde_json::from_str(&payload)?),
+            None => Ok(Value::Null),
+        }
+    }
+
+    /// Sends a request and returns the raw response body, or `None` when the
+    /// successful response had an empty body.
+    ///
+    /// Retries transient failures per the configured [`RetryPolicy`].
+    fn send(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<String>, ClientError> {
+        let mut attempt = 0u32;
+
+        loop {
+            match self.send_once(method.clone(), path, query, body.clone()) {
+                Ok(payload) => return Ok(payload),
+                Err(error) => {
+                    let Some(policy) = &self.retry_policy else {
+                        return Err(error);
+                    };
+
+                    if attempt + 1 >= policy.max_attempts || !policy.is_retryable_method(&method) {
+                        return Err(if attempt > 0 {
+                            ClientError::RetriesExhausted {
+                                attempts: attempt + 1,
+                                source: Box::new(error),
+                            }
+                        } else {
+                            error
+                        });
+                    }
+
+                    let Some(delay) = retry::retryable_delay(&error, policy, attempt) else {
+                        return Err(error);
+                    };
+
+                    attempt += 1;
+                    std::thread::sleep(delay);
+                }
+            }
+        }
+    }
+
+    fn send_once(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<String>, ClientError> {
+        let url = self.build_url_with_query(path, query)?;
         let mut request = self
             .http
             .request(method, url)
             .header(reqwest::header::ACCEPT, "application/json");
 
-        if !query.is_empty() {
-            request = request.query(query);
-        }
-
-        if let Some(token) = &self.authorization_token {
-            request = request.bearer_auth(token);
-        }
+        request = self.apply_auth(request)?;
 
         if let Some(json_body) = body {
             request = request.json(&json_body);
         }
 
+        for middleware in &self.middleware {
+            request = middleware.before_request(request);
+        }
+
         let response = request.send()?;
+        for middleware in &self.middleware {
+            middleware.after_response(&response);
+        }
+
         let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(retry::parse_retry_after);
         let payload = response.text()?;
 
         if !status.is_success() {
             return Err(ClientError::HttpStatus {
                 status,
                 body: payload,
+                retry_after,
+            });
+        }
+
+        if payload.trim().is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(payload))
+        }
+    }
+
+    /// Sends a `GET` request and buffers the raw response body without JSON parsing.
+    ///
+    /// Use this for binary payloads (attachments, exports) that would otherwise be
+    /// forced through `serde_json`. Applies the same middleware and [`RetryPolicy`]
+    /// as every other request.
+    pub fn get_bytes(&self, path: &str) -> Result<Bytes, ClientError> {
+        let response = self.send_response_with_retry(Method::GET, path, |client, url| {
+            Ok(client
+                .http
+                .request(Method::GET, url)
+                .header(reqwest::header::ACCEPT, "*/*"))
+        })?;
+        Ok(response.bytes()?)
+    }
+
+    /// Sends a `multipart/form-data` `POST` request built from `parts` and parses
+    /// the response as JSON.
+    pub fn post_multipart(
+        &self,
+        path: &str,
+        parts: impl IntoIterator<Item = MultipartPart>,
+    ) -> Result<Value, ClientError> {
+        let parts: Vec<MultipartPart> = parts.into_iter().collect();
+        let response = self.send_response_with_retry(Method::POST, path, |client, url| {
+            let mut form = reqwest::blocking::multipart::Form::new();
+            for part in parts.clone() {
+                let mut reqwest_part = reqwest::blocking::multipart::Part::bytes(part.data);
+                if let Some(file_name) = part.file_name {
+                    reqwest_part = reqwest_part.file_name(file_name);
+                }
+                if let Some(content_type) = part.content_type {
+                    reqwest_part = reqwest_part
+                        .mime_str(&content_type)
+                        .map_err(|_| ClientError::InvalidContentType(content_type))?;
+                }
+                form = form.part(part.name, reqwest_part);
+            }
+            Ok(client.http.request(Method::POST, url).multipart(form))
+        })?;
+        Self::parse_json_response(response)
+    }
+
+    /// Sends a `PUT` request with a raw byte body and the given `content_type`.
+    ///
+    /// Parses the response as JSON; use this for uploading attachments/exports
+    /// rather than JSON-encoded payloads.
+    pub fn put_bytes(
+        &self,
+        path: &str,
+        content_type: impl AsRef<str>,
+        body: impl Into<Vec<u8>>,
+    ) -> Result<Value, ClientError> {
+        let content_type = content_type.as_ref().to_owned();
+        let body: Vec<u8> = body.into();
+        let response = self.send_response_with_retry(Method::PUT, path, |client, url| {
+            Ok(client
+                .http
+                .request(Method::PUT, url)
+                .header(reqwest::header::CONTENT_TYPE, &content_type)
+                .body(body.clone()))
+        })?;
+        Self::parse_json_response(response)
+    }
+
+    /// Builds and sends a request via `build`, applying auth, middleware, and
+    /// [`RetryPolicy`] the same way [`Self::send`] does, returning the raw
+    /// successful [`reqwest::blocking::Response`] with its body not yet consumed.
+    ///
+    /// `build` may be called once per attempt, so it must be able to rebuild
+    /// its request body (not just clone a pre-built one) for methods the
+    /// policy retries.
+    fn send_response_with_retry(
+        &self,
+        method: Method,
+        path: &str,
+        build: impl Fn(&Self, Url) -> Result<reqwest::blocking::RequestBuilder, ClientError>,
+    ) -> Result<reqwest::blocking::Response, ClientError> {
+        let mut attempt = 0u32;
+
+        loop {
+            match self.send_response_once(method.clone(), path, &build) {
+                Ok(response) => return Ok(response),
+                Err(error) => {
+                    let Some(policy) = &self.retry_policy else {
+                        return Err(error);
+                    };
+
+                    if attempt + 1 >= policy.max_attempts || !policy.is_retryable_method(&method) {
+                        return Err(if attempt > 0 {
+                            ClientError::RetriesExhausted {
+                                attempts: attempt + 1,
+                                source: Box::new(error),
+                            }
+                        } else {
+                            error
+                        });
+                    }
+
+                    let Some(delay) = retry::retryable_delay(&error, policy, attempt) else {
+                        return Err(error);
+                    };
+
+                    attempt += 1;
+                    std::thread::sleep(delay);
+                }
+            }
+        }
+    }
+
+    fn send_response_once(
+        &self,
+        method: Method,
+        path: &str,
+        build: &impl Fn(&Self, Url) -> Result<reqwest::blocking::RequestBuilder, ClientError>,
+    ) -> Result<reqwest::blocking::Response, ClientError> {
+        let url = self.build_url(path)?;
+        let mut request = build(self, url)?;
+        request = self.apply_auth(request)?;
+
+        for middleware in &self.middleware {
+            request = middleware.before_request(request);
+        }
+
+        let response = request.send()?;
+        for middleware in &self.middleware {
+            middleware.after_response(&response);
+        }
+
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(retry::parse_retry_after);
+            let body = response.text()?;
+            return Err(ClientError::HttpStatus {
+                status,
+                body,
+                retry_after,
             });
         }
 
+        Ok(response)
+    }
+
+    /// Parses a successful response's body as JSON.
+    ///
+    /// `response` must already be known-successful (as returned by
+    /// [`Self::send_response_with_retry`]); this only handles the body.
+    fn parse_json_response(response: reqwest::blocking::Response) -> Result<Value, ClientError> {
+        let payload = response.text()?;
+
         if payload.trim().is_empty() {
             Ok(Value::Null)
         } else {
@@ -110,12 +498,44 @@ impl BlockingApiClient {
         }
     }
 
+    fn apply_auth(
+        &self,
+        mut request: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::RequestBuilder, ClientError> {
+        if let Some(oauth2) = &self.oauth2 {
+            request = request.bearer_auth(oauth2.token()?);
+        } else if let Some(token) = &self.authorization_token {
+            if token.is_expired() {
+                return Err(ClientError::Auth(
+                    "static authorization token has expired; call with_authorization_token again \
+                     with a fresh one, or use with_oauth2_client_credentials for automatic refresh"
+                        .to_owned(),
+                ));
+            }
+            request = request.bearer_auth(&token.value);
+        }
+        Ok(request)
+    }
+
     fn build_url(&self, path: &str) -> Result<Url, ClientError> {
         let relative = path.trim_start_matches('/');
         self.base_url
             .join(relative)
             .map_err(|_| ClientError::InvalidPath(path.to_owned()))
     }
+
+    /// Builds the request URL for `path`, appending `query` as percent-encoded
+    /// query parameters.
+    ///
+    /// See `ApiClient::build_url_with_query` (the async counterpart) for why
+    /// `query_pairs_mut` is used instead of handing raw pairs to `reqwest`.
+    fn build_url_with_query(&self, path: &str, query: &[(&str, &str)]) -> Result<Url, ClientError> {
+        let mut url = self.build_url(path)?;
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+        Ok(url)
+    }
 }
 
 fn ensure_trailing_slash(mut url: Url) -> Url {
@@ -126,3 +546,76 @@ fn ensure_trailing_slash(mut url: Url) -> Url {
     }
     url
 }
+
+/// Deserializes an optional raw response body, distinguishing "no content"
+/// from a parsed value without requiring `T: Default`.
+fn deserialize_as_optional<T: DeserializeOwned>(
+    payload: Option<String>,
+) -> Result<Option<T>, ClientError> {
+    match payload {
+        Some(payload) => Ok(Some(serde_json::from_str(&payload)?)),
+        None => Ok(None),
+    }
+}
+
+/// Deserializes an optional raw response body, mapping an empty body to `T::default()`.
+fn deserialize_as<T: DeserializeOwned + Default>(payload: Option<String>) -> Result<T, ClientError> {
+    Ok(deserialize_as_optional(payload)?.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::{BlockingApiClient, deserialize_as, deserialize_as_optional};
+    use crate::ClientError;
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    struct Widget {
+        id: u32,
+    }
+
+    #[test]
+    fn build_url_with_query_percent_encodes_slashes_unicode_and_reserved_characters() {
+        let client = BlockingApiClient::new("https://example.com/api/").expect("valid url");
+        let url = client
+            .build_url_with_query("items", &[("q", "a/b café &#?")])
+            .expect("valid path");
+        let decoded: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert_eq!(decoded, vec![("q".to_owned(), "a/b café &#?".to_owned())]);
+    }
+
+    #[test]
+    fn deserialize_as_maps_an_empty_body_to_default() {
+        let widget: Widget = deserialize_as(None).expect("empty body is not an error");
+        assert_eq!(widget, Widget::default());
+    }
+
+    #[test]
+    fn deserialize_as_parses_a_non_empty_body() {
+        let widget: Widget = deserialize_as(Some(r#"{"id":7}"#.to_owned())).expect("valid json");
+        assert_eq!(widget, Widget { id: 7 });
+    }
+
+    #[test]
+    fn deserialize_as_surfaces_malformed_json() {
+        let error = deserialize_as::<Widget>(Some("not json".to_owned())).expect_err("should fail");
+        assert!(matches!(error, ClientError::Json(_)));
+    }
+
+    #[test]
+    fn deserialize_as_optional_distinguishes_no_content_from_a_parsed_value() {
+        assert_eq!(deserialize_as_optional::<Widget>(None).expect("empty body"), None);
+        assert_eq!(
+            deserialize_as_optional::<Widget>(Some(r#"{"id":3}"#.to_owned())).expect("valid json"),
+            Some(Widget { id: 3 })
+        );
+    }
+
+    #[test]
+    fn deserialize_as_optional_surfaces_malformed_json() {
+        let error =
+            deserialize_as_optional::<Widget>(Some("not json".to_owned())).expect_err("should fail");
+        assert!(matches!(error, ClientError::Json(_)));
+    }
+}
@@ -1,20 +1,50 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use futures_util::{Stream, StreamExt};
 use reqwest::{Method, Url};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 use crate::ClientError;
+use crate::auth::{OAuth2Config, StaticToken, TokenProvider};
+use crate::middleware::Middleware;
+use crate::multipart::MultipartPart;
+use crate::retry::{self, RetryPolicy};
 
 /// Generic async JSON REST client.
 ///
 /// This client is transport-focused and does not require an `OpenAPI` operation id.
 /// For operation-id based calls generated from `openapi/openapi.json`, use
 /// [`crate::IriClient`].
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct ApiClient {
     base_url: Url,
-    authorization_token: Option<String>,
+    authorization_token: Option<StaticToken>,
+    oauth2: Option<Arc<TokenProvider>>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    retry_policy: Option<RetryPolicy>,
     http: reqwest::Client,
 }
 
+impl fmt::Debug for ApiClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Neither field's value is printed: `authorization_token` is a live bearer
+        // token and `oauth2` carries a client secret plus cached access/refresh
+        // tokens, so only whether each is configured is shown.
+        f.debug_struct("ApiClient")
+            .field("base_url", &self.base_url)
+            .field("authorization_token", &self.authorization_token.is_some())
+            .field("oauth2", &self.oauth2.is_some())
+            .field("middleware_count", &self.middleware.len())
+            .field("retry_policy", &self.retry_policy)
+            .field("http", &self.http)
+            .finish()
+    }
+}
+
 impl ApiClient {
     /// Creates a new client with the given base URL.
     ///
@@ -27,19 +57,131 @@ impl ApiClient {
         Ok(Self {
             base_url: ensure_trailing_slash(parsed),
             authorization_token: None,
+            oauth2: None,
+            middleware: Vec::new(),
+            retry_policy: None,
             http: reqwest::Client::new(),
         })
     }
 
     /// Returns a new client with a raw access token attached to all requests.
     ///
-    /// This sets `Authorization: <token>` (without `Bearer ` prefix).
+    /// This sets `Authorization: Bearer <token>`. The token's
+    /// lifetime is unknown, so it is sent until replaced; use
+    /// [`Self::with_authorization_token_expiring_in`] if the caller knows how
+    /// long it's valid for.
     #[must_use]
     pub fn with_authorization_token(mut self, token: impl Into<String>) -> Self {
-        self.authorization_token = Some(token.into());
+        self.authorization_token = Some(StaticToken::new(token.into()));
+        self
+    }
+
+    /// Returns a new client with a raw access token that is known to expire after `lifetime`.
+    ///
+    /// The token itself is never refreshed (there's no token endpoint to refresh
+    /// it from), but once `lifetime` elapses (minus a small skew) requests fail
+    /// fast with [`ClientError::Auth`] instead of silently sending a token the
+    /// caller already knows is stale. Long-lived processes should catch that and
+    /// call this again with a freshly minted token.
+    #[must_use]
+    pub fn with_authorization_token_expiring_in(mut self, token: impl Into<String>, lifetime: Duration) -> Self {
+        self.authorization_token = Some(StaticToken::with_expiry(token.into(), lifetime));
+        self
+    }
+
+    /// Returns a new client that authenticates via the OAuth2 client-credentials grant.
+    ///
+    /// `scopes` is sent as a single space-separated `scope` parameter when non-empty.
+    /// The resulting access token is cached and transparently refreshed (shortly
+    /// before it expires) ahead of each request; concurrent requests share the
+    /// refresh so they don't stampede the token endpoint. Takes precedence over
+    /// [`Self::with_authorization_token`] when both are set.
+    #[must_use]
+    pub fn with_oauth2_client_credentials(
+        mut self,
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.oauth2 = Some(TokenProvider::new(
+            self.http.clone(),
+            OAuth2Config {
+                token_url: token_url.into(),
+                client_id: client_id.into(),
+                client_secret: client_secret.into(),
+                scopes: scopes.into_iter().map(Into::into).collect(),
+            },
+        ));
+        self
+    }
+
+    /// Registers a [`Middleware`] that observes or rewrites every request/response.
+    ///
+    /// Middlewares run in registration order.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Returns a new client that retries requests on transient failures under `policy`.
+    ///
+    /// Retries connection/timeout errors and `429`/`502`/`503`/`504` responses,
+    /// honoring a `Retry-After` header when present instead of the computed
+    /// backoff. Only `GET`/`HEAD`/`PUT`/`DELETE` are retried by default; see
+    /// [`RetryPolicy::with_retry_post`] to also retry `POST`. When all attempts
+    /// are exhausted, the final error is returned as
+    /// [`ClientError::RetriesExhausted`] so callers can distinguish a flaky
+    /// network from a hard failure.
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Returns a new client backed by a caller-supplied [`reqwest::Client`].
+    ///
+    /// Use this to configure things this crate doesn't expose directly, such as
+    /// a proxy, custom root certificates, or a non-default TLS backend (select
+    /// the `rustls-tls` or `native-tls` Cargo feature to choose which backend
+    /// `reqwest::Client::builder()` uses). Overrides any client built by
+    /// [`Self::with_timeout`]. If [`Self::with_oauth2_client_credentials`] was
+    /// already configured, its token-endpoint requests are switched to this
+    /// client too (dropping any cached token), so the two can be called in
+    /// either order.
+    #[must_use]
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.retarget_oauth2_http_client(http.clone());
+        self.http = http;
         self
     }
 
+    /// Returns a new client with the given per-request timeout.
+    ///
+    /// Replaces the underlying [`reqwest::Client`] with a default one that has
+    /// only this timeout set, discarding a client given to
+    /// [`Self::with_http_client`]; a later [`Self::with_http_client`] call in
+    /// turn discards this timeout. Like [`Self::with_http_client`], this also
+    /// retargets an already-configured OAuth2 token provider.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .expect("reqwest client builder with only a timeout set should not fail");
+        self.retarget_oauth2_http_client(http.clone());
+        self.http = http;
+        self
+    }
+
+    /// Rebuilds the OAuth2 token provider (if any) against `http`.
+    fn retarget_oauth2_http_client(&mut self, http: reqwest::Client) {
+        if let Some(oauth2) = &self.oauth2 {
+            self.oauth2 = Some(oauth2.with_http_client(http));
+        }
+    }
+
     /// Sends a `GET` request and parses the response as JSON.
     pub async fn get_json(&self, path: &str) -> Result<Value, ClientError> {
         self.request_json(Method::GET, path, None).await
@@ -55,6 +197,54 @@ impl ApiClient {
             .await
     }
 
+    /// Sends a `GET` request and deserializes the response into `T`.
+    ///
+    /// An empty successful body deserializes to `T::default()`. Use
+    /// [`Self::request_as_with_query`] for query parameters, or
+    /// [`Self::request_as_optional_with_query`] when `T` has no sensible default.
+    pub async fn get_as<T: DeserializeOwned + Default>(&self, path: &str) -> Result<T, ClientError> {
+        self.request_as(Method::GET, path, None).await
+    }
+
+    /// Sends a request and deserializes the response into `T`.
+    ///
+    /// An empty successful body deserializes to `T::default()`.
+    pub async fn request_as<T: DeserializeOwned + Default>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Value>,
+    ) -> Result<T, ClientError> {
+        self.request_as_with_query(method, path, &[], body).await
+    }
+
+    /// Sends a request with query parameters and deserializes the response into `T`.
+    ///
+    /// An empty successful body deserializes to `T::default()`.
+    pub async fn request_as_with_query<T: DeserializeOwned + Default>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<T, ClientError> {
+        deserialize_as(self.send(method, path, query, body).await?)
+    }
+
+    /// Sends a request with query parameters and deserializes the response into `T`.
+    ///
+    /// Returns `None` for a successful response with an empty body, distinguishing
+    /// "no content" from a parsed value without requiring `T: Default`.
+    pub async fn request_as_optional_with_query<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<T>, ClientError> {
+        deserialize_as_optional(self.send(method, path, query, body).await?)
+    }
+
     /// Sends a `POST` request with a JSON body and parses the response as JSON.
     pub async fn post_json(&self, path: &str, body: Value) -> Result<Value, ClientError> {
         self.request_json(Method::POST, path, Some(body)).await
@@ -92,35 +282,282 @@ impl ApiClient {
         query: &[(&str, &str)],
         body: Option<Value>,
     ) -> Result<Value, ClientError> {
-        let url = self.build_url(path)?;
+        match self.send(method, path, query, body).await? {
+            Some(payload) => Ok(serde_json::from_str(&payload)?),
+            None => Ok(Value::Null),
+        }
+    }
+
+    /// Sends a request and returns the raw response body, or `None` when the
+    /// successful response had an empty body.
+    ///
+    /// Retries transient failures per the configured [`RetryPolicy`].
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<String>, ClientError> {
+        let mut attempt = 0u32;
+
+        loop {
+            match self.send_once(method.clone(), path, query, body.clone()).await {
+                Ok(payload) => return Ok(payload),
+                Err(error) => {
+                    let Some(policy) = &self.retry_policy else {
+                        return Err(error);
+                    };
+
+                    if attempt + 1 >= policy.max_attempts || !policy.is_retryable_method(&method) {
+                        return Err(if attempt > 0 {
+                            ClientError::RetriesExhausted {
+                                attempts: attempt + 1,
+                                source: Box::new(error),
+                            }
+                        } else {
+                            error
+                        });
+                    }
+
+                    let Some(delay) = retry::retryable_delay(&error, policy, attempt) else {
+                        return Err(error);
+                    };
+
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    async fn send_once(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<String>, ClientError> {
+        let url = self.build_url_with_query(path, query)?;
         let mut request = self
             .http
             .request(method, url)
             .header(reqwest::header::ACCEPT, "application/json");
 
-        if !query.is_empty() {
-            request = request.query(query);
-        }
-
-        if let Some(token) = &self.authorization_token {
-            request = request.bearer_auth(token);
-        }
+        request = self.apply_auth(request).await?;
 
         if let Some(json_body) = body {
             request = request.json(&json_body);
         }
 
+        for middleware in &self.middleware {
+            request = middleware.before_request(request);
+        }
+
         let response = request.send().await?;
+        for middleware in &self.middleware {
+            middleware.after_response(&response);
+        }
+
         let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(retry::parse_retry_after);
         let payload = response.text().await?;
 
         if !status.is_success() {
             return Err(ClientError::HttpStatus {
                 status,
                 body: payload,
+                retry_after,
+            });
+        }
+
+        if payload.trim().is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(payload))
+        }
+    }
+
+    /// Sends a `GET` request and streams the raw response body without JSON parsing.
+    ///
+    /// Use this for binary payloads (attachments, exports) that would otherwise be
+    /// forced through `serde_json`. Use [`Self::get_bytes`] for a buffered variant.
+    /// Applies the same middleware and [`RetryPolicy`] as every other request; a
+    /// retry only ever happens before any bytes are streamed to the caller.
+    pub async fn get_bytes_stream(
+        &self,
+        path: &str,
+    ) -> Result<impl Stream<Item = Result<Bytes, ClientError>>, ClientError> {
+        let response = self
+            .send_response_with_retry(Method::GET, path, |client, url| {
+                Ok(client
+                    .http
+                    .request(Method::GET, url)
+                    .header(reqwest::header::ACCEPT, "*/*"))
+            })
+            .await?;
+
+        Ok(response
+            .bytes_stream()
+            .map(|chunk| chunk.map_err(ClientError::from)))
+    }
+
+    /// Sends a `GET` request and buffers the raw response body without JSON parsing.
+    pub async fn get_bytes(&self, path: &str) -> Result<Bytes, ClientError> {
+        let mut stream = Box::pin(self.get_bytes_stream(path).await?);
+        let mut buffer = BytesMut::new();
+        while let Some(chunk) = stream.next().await {
+            buffer.extend_from_slice(&chunk?);
+        }
+        Ok(buffer.freeze())
+    }
+
+    /// Sends a `multipart/form-data` `POST` request built from `parts` and parses
+    /// the response as JSON.
+    pub async fn post_multipart(
+        &self,
+        path: &str,
+        parts: impl IntoIterator<Item = MultipartPart>,
+    ) -> Result<Value, ClientError> {
+        let parts: Vec<MultipartPart> = parts.into_iter().collect();
+        let response = self
+            .send_response_with_retry(Method::POST, path, |client, url| {
+                let mut form = reqwest::multipart::Form::new();
+                for part in parts.clone() {
+                    let mut reqwest_part = reqwest::multipart::Part::bytes(part.data);
+                    if let Some(file_name) = part.file_name {
+                        reqwest_part = reqwest_part.file_name(file_name);
+                    }
+                    if let Some(content_type) = part.content_type {
+                        reqwest_part = reqwest_part
+                            .mime_str(&content_type)
+                            .map_err(|_| ClientError::InvalidContentType(content_type))?;
+                    }
+                    form = form.part(part.name, reqwest_part);
+                }
+                Ok(client.http.request(Method::POST, url).multipart(form))
+            })
+            .await?;
+        Self::parse_json_response(response).await
+    }
+
+    /// Sends a `PUT` request with a raw byte body and the given `content_type`.
+    ///
+    /// Parses the response as JSON; use this for uploading attachments/exports
+    /// rather than JSON-encoded payloads.
+    pub async fn put_bytes(
+        &self,
+        path: &str,
+        content_type: impl AsRef<str>,
+        body: impl Into<Vec<u8>>,
+    ) -> Result<Value, ClientError> {
+        let content_type = content_type.as_ref().to_owned();
+        let body: Vec<u8> = body.into();
+        let response = self
+            .send_response_with_retry(Method::PUT, path, |client, url| {
+                Ok(client
+                    .http
+                    .request(Method::PUT, url)
+                    .header(reqwest::header::CONTENT_TYPE, &content_type)
+                    .body(body.clone()))
+            })
+            .await?;
+        Self::parse_json_response(response).await
+    }
+
+    /// Builds and sends a request via `build`, applying auth, middleware, and
+    /// [`RetryPolicy`] the same way [`Self::send`] does, returning the raw
+    /// successful [`reqwest::Response`] with its body not yet consumed.
+    ///
+    /// `build` may be called once per attempt, so it must be able to rebuild
+    /// its request body (not just clone a pre-built one) for methods the
+    /// policy retries.
+    async fn send_response_with_retry(
+        &self,
+        method: Method,
+        path: &str,
+        build: impl Fn(&Self, Url) -> Result<reqwest::RequestBuilder, ClientError>,
+    ) -> Result<reqwest::Response, ClientError> {
+        let mut attempt = 0u32;
+
+        loop {
+            match self.send_response_once(method.clone(), path, &build).await {
+                Ok(response) => return Ok(response),
+                Err(error) => {
+                    let Some(policy) = &self.retry_policy else {
+                        return Err(error);
+                    };
+
+                    if attempt + 1 >= policy.max_attempts || !policy.is_retryable_method(&method) {
+                        return Err(if attempt > 0 {
+                            ClientError::RetriesExhausted {
+                                attempts: attempt + 1,
+                                source: Box::new(error),
+                            }
+                        } else {
+                            error
+                        });
+                    }
+
+                    let Some(delay) = retry::retryable_delay(&error, policy, attempt) else {
+                        return Err(error);
+                    };
+
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    async fn send_response_once(
+        &self,
+        method: Method,
+        path: &str,
+        build: &impl Fn(&Self, Url) -> Result<reqwest::RequestBuilder, ClientError>,
+    ) -> Result<reqwest::Response, ClientError> {
+        let url = self.build_url(path)?;
+        let mut request = build(self, url)?;
+        request = self.apply_auth(request).await?;
+
+        for middleware in &self.middleware {
+            request = middleware.before_request(request);
+        }
+
+        let response = request.send().await?;
+        for middleware in &self.middleware {
+            middleware.after_response(&response);
+        }
+
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(retry::parse_retry_after);
+            let body = response.text().await?;
+            return Err(ClientError::HttpStatus {
+                status,
+                body,
+                retry_after,
             });
         }
 
+        Ok(response)
+    }
+
+    /// Parses a successful response's body as JSON.
+    ///
+    /// `response` must already be known-successful (as returned by
+    /// [`Self::send_response_with_retry`]); this only handles the body.
+    async fn parse_json_response(response: reqwest::Response) -> Result<Value, ClientError> {
+        let payload = response.text().await?;
+
         if payload.trim().is_empty() {
             Ok(Value::Null)
         } else {
@@ -128,12 +565,47 @@ impl ApiClient {
         }
     }
 
+    async fn apply_auth(
+        &self,
+        mut request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::RequestBuilder, ClientError> {
+        if let Some(oauth2) = &self.oauth2 {
+            request = request.bearer_auth(oauth2.token().await?);
+        } else if let Some(token) = &self.authorization_token {
+            if token.is_expired() {
+                return Err(ClientError::Auth(
+                    "static authorization token has expired; call with_authorization_token again \
+                     with a fresh one, or use with_oauth2_client_credentials for automatic refresh"
+                        .to_owned(),
+                ));
+            }
+            request = request.bearer_auth(&token.value);
+        }
+        Ok(request)
+    }
+
     fn build_url(&self, path: &str) -> Result<Url, ClientError> {
         let relative = path.trim_start_matches('/');
         self.base_url
             .join(relative)
             .map_err(|_| ClientError::InvalidPath(path.to_owned()))
     }
+
+    /// Builds the request URL for `path`, appending `query` as percent-encoded
+    /// query parameters.
+    ///
+    /// Query values go through [`Url::query_pairs_mut`] (the same
+    /// `form_urlencoded` encoding `reqwest`'s own `RequestBuilder::query` uses),
+    /// so a value containing a slash, unicode, or a reserved character like `&`
+    /// or `#` is encoded rather than corrupting the query string or bleeding
+    /// into an adjacent parameter.
+    fn build_url_with_query(&self, path: &str, query: &[(&str, &str)]) -> Result<Url, ClientError> {
+        let mut url = self.build_url(path)?;
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+        Ok(url)
+    }
 }
 
 fn ensure_trailing_slash(mut url: Url) -> Url {
@@ -145,9 +617,33 @@ fn ensure_trailing_slash(mut url: Url) -> Url {
     url
 }
 
+/// Deserializes an optional raw response body, distinguishing "no content"
+/// from a parsed value without requiring `T: Default`.
+fn deserialize_as_optional<T: DeserializeOwned>(
+    payload: Option<String>,
+) -> Result<Option<T>, ClientError> {
+    match payload {
+        Some(payload) => Ok(Some(serde_json::from_str(&payload)?)),
+        None => Ok(None),
+    }
+}
+
+/// Deserializes an optional raw response body, mapping an empty body to `T::default()`.
+fn deserialize_as<T: DeserializeOwned + Default>(payload: Option<String>) -> Result<T, ClientError> {
+    Ok(deserialize_as_optional(payload)?.unwrap_or_default())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::ApiClient;
+    use serde::Deserialize;
+
+    use super::{ApiClient, deserialize_as, deserialize_as_optional};
+    use crate::ClientError;
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    struct Widget {
+        id: u32,
+    }
 
     #[test]
     fn joins_paths_from_base_with_nested_prefix() {
@@ -155,4 +651,83 @@ mod tests {
         let resolved = client.build_url("items").expect("valid path");
         assert_eq!(resolved.as_str(), "https://example.com/api/v1/items");
     }
+
+    #[test]
+    fn build_url_with_query_percent_encodes_slashes() {
+        let client = ApiClient::new("https://example.com/api/").expect("valid url");
+        let url = client
+            .build_url_with_query("items", &[("id", "a/b")])
+            .expect("valid path");
+        assert_eq!(url.query(), Some("id=a%2Fb"));
+    }
+
+    #[test]
+    fn build_url_with_query_percent_encodes_unicode() {
+        let client = ApiClient::new("https://example.com/api/").expect("valid url");
+        let url = client
+            .build_url_with_query("items", &[("name", "café")])
+            .expect("valid path");
+        assert_eq!(url.query(), Some("name=caf%C3%A9"));
+    }
+
+    #[test]
+    fn build_url_with_query_percent_encodes_reserved_and_space_characters() {
+        let client = ApiClient::new("https://example.com/api/").expect("valid url");
+        let url = client
+            .build_url_with_query("items", &[("q", "a b&c#d")])
+            .expect("valid path");
+        assert_eq!(url.query(), Some("q=a+b%26c%23d"));
+    }
+
+    #[test]
+    fn build_url_with_query_round_trips_back_to_the_original_value() {
+        let client = ApiClient::new("https://example.com/api/").expect("valid url");
+        let original = "a/b café &#?";
+        let url = client
+            .build_url_with_query("items", &[("q", original)])
+            .expect("valid path");
+        let decoded: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert_eq!(decoded, vec![("q".to_owned(), original.to_owned())]);
+    }
+
+    #[test]
+    fn build_url_with_query_is_unchanged_when_query_is_empty() {
+        let client = ApiClient::new("https://example.com/api/").expect("valid url");
+        let url = client.build_url_with_query("items", &[]).expect("valid path");
+        assert_eq!(url.query(), None);
+    }
+
+    #[test]
+    fn deserialize_as_maps_an_empty_body_to_default() {
+        let widget: Widget = deserialize_as(None).expect("empty body is not an error");
+        assert_eq!(widget, Widget::default());
+    }
+
+    #[test]
+    fn deserialize_as_parses_a_non_empty_body() {
+        let widget: Widget = deserialize_as(Some(r#"{"id":7}"#.to_owned())).expect("valid json");
+        assert_eq!(widget, Widget { id: 7 });
+    }
+
+    #[test]
+    fn deserialize_as_surfaces_malformed_json() {
+        let error = deserialize_as::<Widget>(Some("not json".to_owned())).expect_err("should fail");
+        assert!(matches!(error, ClientError::Json(_)));
+    }
+
+    #[test]
+    fn deserialize_as_optional_distinguishes_no_content_from_a_parsed_value() {
+        assert_eq!(deserialize_as_optional::<Widget>(None).expect("empty body"), None);
+        assert_eq!(
+            deserialize_as_optional::<Widget>(Some(r#"{"id":3}"#.to_owned())).expect("valid json"),
+            Some(Widget { id: 3 })
+        );
+    }
+
+    #[test]
+    fn deserialize_as_optional_surfaces_malformed_json() {
+        let error =
+            deserialize_as_optional::<Widget>(Some("not json".to_owned())).expect_err("should fail");
+        assert!(matches!(error, ClientError::Json(_)));
+    }
 }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use thiserror::Error;
 
 /// Errors returned by REST client operations.
@@ -35,5 +37,37 @@ pub enum ClientError {
     HttpStatus {
         status: reqwest::StatusCode,
         body: String,
+        /// Delay requested by the server's `Retry-After` header, if present.
+        retry_after: Option<Duration>,
+    },
+
+    /// OAuth2 token acquisition or refresh failed.
+    #[error("authentication failed: {0}")]
+    Auth(String),
+
+    /// A paginated response did not match the configured [`crate::PaginationConfig`].
+    #[error("unexpected pagination response shape: {0}")]
+    UnexpectedPaginationShape(String),
+
+    /// The configured [`crate::RetryPolicy`] was exhausted; wraps the final error.
+    #[error("request failed after {attempts} attempt(s): {source}")]
+    RetriesExhausted {
+        attempts: u32,
+        #[source]
+        source: Box<ClientError>,
     },
+
+    /// An operation was looked up through a tag-scoped accessor (for example
+    /// `IriClient::tag`) that doesn't carry that `OpenAPI` tag.
+    #[error("operation '{operation_id}' is not tagged '{tag}'")]
+    OperationNotInTag { operation_id: String, tag: String },
+
+    /// `paginate`/`paginate_with_config` was called with a zero page size, which
+    /// would never advance the offset or see a short page to stop on.
+    #[error("page_size must be greater than zero")]
+    InvalidPageSize(u32),
+
+    /// A multipart part's `content_type` is not a valid MIME type.
+    #[error("invalid content type '{0}'")]
+    InvalidContentType(String),
 }
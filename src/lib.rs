@@ -2,16 +2,26 @@
 //!
 //! Public API layers:
 //! - [`ApiClient`]/[`BlockingApiClient`]: generic JSON HTTP clients.
-//! - [`IriClient`]/[`BlockingIriClient`]: OpenAPI-driven operation clients.
+//! - [`IriClient`]/[`BlockingIriClient`]: OpenAPI-driven operation clients,
+//!   addressed by `operation_id` string (`call_operation`) or tag
+//!   (`IriClient::tag`).
+//! - [`tags`]: generated, per-tag accessors with one compile-time-checked
+//!   method per operation (`client.projects().get_projects()`).
 //! - [`ClientError`]: unified error type used by all clients.
 //!
-//! The `OpenAPI` operation registry is generated at build time from
-//! `openapi/openapi.json`.
+//! The `OpenAPI` operation registry and the [`tags`] module are generated
+//! at build time from `openapi/openapi.json`; see `build.rs`.
 
+mod auth;
 mod blocking_client;
 mod client;
 mod error;
+mod middleware;
+mod multipart;
 mod openapi_client;
+mod pagination;
+mod rate_limit;
+mod retry;
 
 /// Generic blocking JSON REST client.
 pub use blocking_client::BlockingApiClient;
@@ -19,12 +29,38 @@ pub use blocking_client::BlockingApiClient;
 pub use client::ApiClient;
 /// Error type returned by all client operations.
 pub use error::ClientError;
+/// Request/response observer hooks.
+pub use middleware::{BlockingMiddleware, Middleware};
+/// One field of a `multipart/form-data` request body.
+pub use multipart::MultipartPart;
+/// Configuration for limit/offset pagination helpers.
+pub use pagination::PaginationConfig;
+/// Client-side rate limit for `IriClient::call_operation`.
+pub use rate_limit::RateLimit;
+/// Retry policy for transient failures.
+pub use retry::RetryPolicy;
 /// OpenAPI-backed blocking operation client.
 ///
 /// See also [`IriClient`] for the async variant.
 pub use openapi_client::{
-    BlockingIriClient, IriClient, OperationDefinition, openapi_default_server_url,
+    BlockingIriClient, BlockingIriClientTag, IriClient, IriClientTag, OperationDefinition,
+    openapi_default_server_url,
 };
+/// Generated, per-`OpenAPI`-tag operation accessors; see the module docs.
+pub use openapi_client::tags;
+
+/// Synchronous client surface, for consumers that don't want to pull in a
+/// Tokio runtime just to call an operation from a CLI or script.
+///
+/// Re-exports the same [`BlockingApiClient`]/[`BlockingIriClient`] types
+/// available at the crate root, under names that mirror their async
+/// counterparts (`blocking::ApiClient`, `blocking::IriClient`) so call
+/// sites read the same regardless of which variant is in scope.
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    pub use crate::blocking_client::BlockingApiClient as ApiClient;
+    pub use crate::openapi_client::BlockingIriClient as IriClient;
+}
 
 #[cfg(feature = "python")]
 mod python;
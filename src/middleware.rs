@@ -0,0 +1,33 @@
+/// Observes and can rewrite outgoing requests for every call made through
+/// [`crate::ApiClient`].
+///
+/// Register via `ApiClient::with_middleware`. Multiple middlewares run in
+/// registration order for `before_request` and the same order for
+/// `after_response`.
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent; may add headers, change timeouts, etc.
+    fn before_request(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        request
+    }
+
+    /// Called after a response is received, including non-success statuses.
+    ///
+    /// The response body has not been consumed yet.
+    fn after_response(&self, _response: &reqwest::Response) {}
+}
+
+/// Blocking counterpart of [`Middleware`], used by [`crate::BlockingApiClient`].
+pub trait BlockingMiddleware: Send + Sync {
+    /// Called before the request is sent; may add headers, change timeouts, etc.
+    fn before_request(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> reqwest::blocking::RequestBuilder {
+        request
+    }
+
+    /// Called after a response is received, including non-success statuses.
+    ///
+    /// The response body has not been consumed yet.
+    fn after_response(&self, _response: &reqwest::blocking::Response) {}
+}
@@ -0,0 +1,63 @@
+/// One field of a `multipart/form-data` request body.
+///
+/// Used by `ApiClient::post_multipart` and `BlockingApiClient::post_multipart`.
+#[derive(Clone, Debug)]
+pub struct MultipartPart {
+    /// Form field name.
+    pub name: String,
+    /// Optional file name advertised in the part's `Content-Disposition`.
+    pub file_name: Option<String>,
+    /// Optional MIME type advertised in the part's `Content-Type`.
+    pub content_type: Option<String>,
+    /// Raw field contents.
+    pub data: Vec<u8>,
+}
+
+impl MultipartPart {
+    /// Creates a plain form field (no file name or content type).
+    pub fn new(name: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            name: name.into(),
+            file_name: None,
+            content_type: None,
+            data: data.into(),
+        }
+    }
+
+    /// Sets the file name advertised for this part.
+    #[must_use]
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Sets the content type advertised for this part.
+    #[must_use]
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultipartPart;
+
+    #[test]
+    fn new_leaves_file_name_and_content_type_unset() {
+        let part = MultipartPart::new("field", b"payload".to_vec());
+        assert_eq!(part.name, "field");
+        assert_eq!(part.data, b"payload");
+        assert!(part.file_name.is_none());
+        assert!(part.content_type.is_none());
+    }
+
+    #[test]
+    fn with_file_name_and_content_type_set_both_fields() {
+        let part = MultipartPart::new("file", b"bytes".to_vec())
+            .with_file_name("report.pdf")
+            .with_content_type("application/pdf");
+        assert_eq!(part.file_name.as_deref(), Some("report.pdf"));
+        assert_eq!(part.content_type.as_deref(), Some("application/pdf"));
+    }
+}
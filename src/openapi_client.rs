@@ -1,9 +1,41 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::Stream;
+use futures_util::stream;
+use governor::DefaultDirectRateLimiter;
+use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
 use reqwest::Method;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
-use url::form_urlencoded::byte_serialize;
 
+use crate::pagination::PaginationConfig;
+use crate::rate_limit::{self, RateLimit};
+use crate::retry::RetryPolicy;
 use crate::{ApiClient, BlockingApiClient, ClientError};
 
+/// Characters that must be percent-encoded within a single URL path segment.
+///
+/// Controls, space, `"`, `<`, `>`, `` ` ``, `#`, `?`, `{` and `}` (non-ASCII
+/// bytes are always escaped), plus `/`, `\` and `%`, so a path-parameter value
+/// (for example a resource id) can never introduce an extra path segment
+/// (`\` counts as a separator in `http`/`https` URLs) or an
+/// already-encoded-looking sequence. Other ASCII punctuation is left as is.
+const PATH_SEGMENT_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'#')
+    .add(b'?')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'\\')
+    .add(b'%');
+
 /// Metadata for one `OpenAPI` operation.
 ///
 /// Values are generated from `openapi/openapi.json` at build time.
@@ -17,6 +49,8 @@ pub struct OperationDefinition {
     pub path_template: &'static str,
     /// Required path parameter names extracted from `path_template`.
     pub path_params: &'static [&'static str],
+    /// `OpenAPI` tags this operation belongs to, in spec order.
+    pub tags: &'static [&'static str],
 }
 
 // Generated file contract (`$OUT_DIR/openapi_operations.rs`):
@@ -28,6 +62,14 @@ pub struct OperationDefinition {
 //      - `method` (uppercase)
 //      - `path_template`
 //      - `path_params`
+//      - `tags`
+// 3. `pub mod tags { ... }`
+//    - One `{Tag}Operations`/`Blocking{Tag}Operations` struct per `OpenAPI`
+//      tag, with a named, typed method (and a `*_as::<T>` twin) per
+//      operation carrying that tag.
+// 4. `impl IriClient` / `impl BlockingIriClient`
+//    - One accessor method per tag (for example `fn projects(&self) ->
+//      tags::ProjectsOperations<'_>`) returning the struct from (3).
 //
 // This contract is produced by `build.rs` and consumed by this module via `include!`.
 include!(concat!(env!("OUT_DIR"), "/openapi_operations.rs"));
@@ -36,9 +78,19 @@ include!(concat!(env!("OUT_DIR"), "/openapi_operations.rs"));
 ///
 /// Use this when you want to call endpoints via `operation_id` rather than
 /// hard-coded URL paths.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct IriClient {
     inner: ApiClient,
+    rate_limiter: Option<Arc<DefaultDirectRateLimiter>>,
+}
+
+impl std::fmt::Debug for IriClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IriClient")
+            .field("inner", &self.inner)
+            .field("rate_limited", &self.rate_limiter.is_some())
+            .finish()
+    }
 }
 
 impl IriClient {
@@ -46,6 +98,7 @@ impl IriClient {
     pub fn new(base_url: impl AsRef<str>) -> Result<Self, ClientError> {
         Ok(Self {
             inner: ApiClient::new(base_url)?,
+            rate_limiter: None,
         })
     }
 
@@ -54,6 +107,22 @@ impl IriClient {
         Self::new(openapi_default_server_url())
     }
 
+    /// Creates a client at `base_url` authenticated via the OAuth2 client-credentials grant.
+    ///
+    /// Equivalent to `Self::new(base_url)?.with_oauth2_client_credentials(...)`. This
+    /// performs the client-credentials grant itself (POSTing to `token_url` and
+    /// caching/refreshing the resulting bearer token ahead of expiry); the
+    /// authorization-code grant (browser redirect/callback) is not implemented.
+    pub fn from_oauth_client_credentials(
+        base_url: impl AsRef<str>,
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, ClientError> {
+        Ok(Self::new(base_url)?.with_oauth2_client_credentials(token_url, client_id, client_secret, scopes))
+    }
+
     /// Returns a new client with a raw access token attached to all requests.
     ///
     /// This sets `Authorization: <token>` (without `Bearer ` prefix).
@@ -63,6 +132,71 @@ impl IriClient {
         self
     }
 
+    /// Returns a new client with a raw access token that is known to expire after `lifetime`.
+    ///
+    /// See [`ApiClient::with_authorization_token_expiring_in`].
+    #[must_use]
+    pub fn with_authorization_token_expiring_in(mut self, token: impl Into<String>, lifetime: Duration) -> Self {
+        self.inner = self.inner.with_authorization_token_expiring_in(token, lifetime);
+        self
+    }
+
+    /// Returns a new client that authenticates via the OAuth2 client-credentials grant.
+    ///
+    /// See [`ApiClient::with_oauth2_client_credentials`].
+    #[must_use]
+    pub fn with_oauth2_client_credentials(
+        mut self,
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .with_oauth2_client_credentials(token_url, client_id, client_secret, scopes);
+        self
+    }
+
+    /// Returns a new client backed by a caller-supplied [`reqwest::Client`].
+    ///
+    /// See [`ApiClient::with_http_client`].
+    #[must_use]
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.inner = self.inner.with_http_client(http);
+        self
+    }
+
+    /// Returns a new client with the given per-request timeout.
+    ///
+    /// See [`ApiClient::with_timeout`].
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.with_timeout(timeout);
+        self
+    }
+
+    /// Returns a new client that rate-limits `call_operation`/`call_operation_as`.
+    ///
+    /// Each call awaits a permit under `limit` before dispatching its request, so
+    /// loops over many operations (or paginated listings) don't trip the server's
+    /// own throttling. A no-op until this is called.
+    #[must_use]
+    pub fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limiter = Some(Arc::new(rate_limit::build_limiter(limit)));
+        self
+    }
+
+    /// Returns a new client that retries `call_operation`/`call_operation_as` on
+    /// transient failures under `policy`.
+    ///
+    /// See [`ApiClient::with_retry`] for details.
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.inner = self.inner.with_retry(policy);
+        self
+    }
+
     /// Returns all operations discovered from the `OpenAPI` spec.
     pub fn operations() -> &'static [OperationDefinition] {
         OPENAPI_OPERATIONS
@@ -87,7 +221,8 @@ impl IriClient {
     ///
     /// `path_params` replaces `{param}` segments in the operation path template.
     /// Missing required parameters return
-    /// [`ClientError::MissingPathParameter`].
+    /// [`ClientError::MissingPathParameter`]. Awaits a permit from the configured
+    /// rate limiter, if any, before dispatching.
     pub async fn call_operation(
         &self,
         operation_id: &str,
@@ -98,18 +233,250 @@ impl IriClient {
         let operation = find_operation(operation_id)?;
         let rendered_path = render_path(operation, path_params)?;
         let method = parse_method(operation)?;
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.until_ready().await;
+        }
         self.inner
             .request_json_with_query(method, &rendered_path, query, body)
             .await
     }
+
+    /// Calls an endpoint by `OpenAPI` `operation_id` and deserializes the response into `T`.
+    ///
+    /// An empty successful body deserializes to `T::default()`. Awaits a permit
+    /// from the configured rate limiter, if any, before dispatching.
+    pub async fn call_operation_as<T: DeserializeOwned + Default>(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<T, ClientError> {
+        let operation = find_operation(operation_id)?;
+        let rendered_path = render_path(operation, path_params)?;
+        let method = parse_method(operation)?;
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.until_ready().await;
+        }
+        self.inner
+            .request_as_with_query(method, &rendered_path, query, body)
+            .await
+    }
+
+    /// Calls an endpoint by `OpenAPI` `operation_id` and deserializes the response into `T`.
+    ///
+    /// Returns `None` for a successful response with an empty body, distinguishing
+    /// "no content" from a parsed value without requiring `T: Default`. Awaits a
+    /// permit from the configured rate limiter, if any, before dispatching.
+    pub async fn call_operation_as_optional<T: DeserializeOwned>(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<T>, ClientError> {
+        let operation = find_operation(operation_id)?;
+        let rendered_path = render_path(operation, path_params)?;
+        let method = parse_method(operation)?;
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.until_ready().await;
+        }
+        self.inner
+            .request_as_optional_with_query(method, &rendered_path, query, body)
+            .await
+    }
+
+    /// Scopes `call_operation`/`call_operation_as` to operations carrying
+    /// `tag` (for example `client.tag("projects").call_operation(...)`),
+    /// rejecting any `operation_id` not in that `OpenAPI` tag with
+    /// [`ClientError::OperationNotInTag`].
+    ///
+    /// `build.rs` also emits a generated, per-tag accessor with one
+    /// compile-time-checked method per operation — see the [`crate::tags`]
+    /// module, reachable per-tag as `client.projects()` (spec-dependent;
+    /// named after the tag, not reproduced here). Reach for `tag` instead
+    /// when the operation to call isn't known until runtime (a CLI driven
+    /// by `--operation-id`, generic tooling over
+    /// [`Self::operations_by_tag`]), since it still takes `operation_id`
+    /// and parameters as strings.
+    pub fn tag<'a>(&'a self, tag: &'a str) -> IriClientTag<'a> {
+        IriClientTag { client: self, tag }
+    }
+
+    /// Returns the operations tagged `tag`, in catalog order.
+    pub fn operations_by_tag(tag: &str) -> impl Iterator<Item = &'static OperationDefinition> + '_ {
+        OPENAPI_OPERATIONS
+            .iter()
+            .filter(move |op| op.tags.contains(&tag))
+    }
+
+    /// Sends a `GET` request and buffers the raw response body without JSON parsing.
+    ///
+    /// See [`ApiClient::get_bytes`].
+    pub async fn get_bytes(&self, path: &str) -> Result<bytes::Bytes, ClientError> {
+        self.inner.get_bytes(path).await
+    }
+
+    /// Pages through a `limit`/`offset` operation, yielding each item across pages.
+    ///
+    /// Stops once a page returns fewer than `page_size` items (including an empty
+    /// page). Uses the default [`PaginationConfig`] (`"limit"`/`"offset"`, items at
+    /// the JSON root array); see [`Self::paginate_with_config`] to adapt this to
+    /// wrapped envelopes like `{ "items": [...] }`.
+    pub fn paginate<'a>(
+        &'a self,
+        operation_id: impl Into<String>,
+        path_params: &[(&str, &str)],
+        base_query: &[(&str, &str)],
+        page_size: u32,
+    ) -> impl Stream<Item = Result<Value, ClientError>> + 'a {
+        self.paginate_with_config(
+            operation_id,
+            path_params,
+            base_query,
+            page_size,
+            PaginationConfig::default(),
+        )
+    }
+
+    /// Like [`Self::paginate`], with a custom [`PaginationConfig`].
+    pub fn paginate_with_config<'a>(
+        &'a self,
+        operation_id: impl Into<String>,
+        path_params: &[(&str, &str)],
+        base_query: &[(&str, &str)],
+        page_size: u32,
+        config: PaginationConfig,
+    ) -> impl Stream<Item = Result<Value, ClientError>> + 'a {
+        let state = PaginationState {
+            client: self,
+            operation_id: operation_id.into(),
+            path_params: owned_pairs(path_params),
+            base_query: owned_pairs(base_query),
+            page_size,
+            config,
+            offset: 0,
+            buffered: VecDeque::new(),
+            done: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.buffered.pop_front() {
+                    return Some((Ok(item), state));
+                }
+                if state.done {
+                    return None;
+                }
+                if let Err(error) = validate_page_size(state.page_size) {
+                    state.done = true;
+                    return Some((Err(error), state));
+                }
+
+                let query = state.next_page_query();
+                let path_param_refs = as_str_pairs(&state.path_params);
+                let query_refs = as_str_pairs(&query);
+
+                let page = match state
+                    .client
+                    .call_operation(&state.operation_id, &path_param_refs, &query_refs, None)
+                    .await
+                {
+                    Ok(page) => page,
+                    Err(error) => {
+                        state.done = true;
+                        return Some((Err(error), state));
+                    }
+                };
+
+                match state.config.extract_items(page) {
+                    Ok(items) => state.absorb_page(items),
+                    Err(error) => {
+                        state.done = true;
+                        return Some((Err(error), state));
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// A view of [`IriClient`] scoped to operations carrying one `OpenAPI` tag.
+///
+/// Returned by [`IriClient::tag`]. Keeps `call_operation`/`call_operation_as`
+/// available, but rejects `operation_id`s outside this tag up front instead
+/// of leaving a typo to surface as a confusing downstream HTTP error.
+#[derive(Debug)]
+pub struct IriClientTag<'a> {
+    client: &'a IriClient,
+    tag: &'a str,
+}
+
+impl IriClientTag<'_> {
+    /// Calls `operation_id` if it carries this tag.
+    ///
+    /// See [`IriClient::call_operation`] for details.
+    pub async fn call_operation(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Value, ClientError> {
+        find_operation_in_tag(self.tag, operation_id)?;
+        self.client
+            .call_operation(operation_id, path_params, query, body)
+            .await
+    }
+
+    /// Calls `operation_id` if it carries this tag, deserializing the response into `T`.
+    ///
+    /// See [`IriClient::call_operation_as`] for details.
+    pub async fn call_operation_as<T: DeserializeOwned + Default>(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<T, ClientError> {
+        find_operation_in_tag(self.tag, operation_id)?;
+        self.client
+            .call_operation_as(operation_id, path_params, query, body)
+            .await
+    }
+
+    /// Calls `operation_id` if it carries this tag, deserializing the response into `T`.
+    ///
+    /// See [`IriClient::call_operation_as_optional`] for details.
+    pub async fn call_operation_as_optional<T: DeserializeOwned>(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<T>, ClientError> {
+        find_operation_in_tag(self.tag, operation_id)?;
+        self.client
+            .call_operation_as_optional(operation_id, path_params, query, body)
+            .await
+    }
 }
 
 /// Blocking IRI API client backed by the `OpenAPI` operation registry.
 ///
 /// This is the synchronous counterpart of [`IriClient`].
-#[derive(Debug)]
 pub struct BlockingIriClient {
     inner: BlockingApiClient,
+    rate_limiter: Option<Arc<DefaultDirectRateLimiter>>,
+}
+
+impl std::fmt::Debug for BlockingIriClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockingIriClient")
+            .field("inner", &self.inner)
+            .field("rate_limited", &self.rate_limiter.is_some())
+            .finish()
+    }
 }
 
 impl BlockingIriClient {
@@ -117,6 +484,7 @@ impl BlockingIriClient {
     pub fn new(base_url: impl AsRef<str>) -> Result<Self, ClientError> {
         Ok(Self {
             inner: BlockingApiClient::new(base_url)?,
+            rate_limiter: None,
         })
     }
 
@@ -125,6 +493,19 @@ impl BlockingIriClient {
         Self::new(openapi_default_server_url())
     }
 
+    /// Creates a client at `base_url` authenticated via the OAuth2 client-credentials grant.
+    ///
+    /// See [`IriClient::from_oauth_client_credentials`] for details.
+    pub fn from_oauth_client_credentials(
+        base_url: impl AsRef<str>,
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, ClientError> {
+        Ok(Self::new(base_url)?.with_oauth2_client_credentials(token_url, client_id, client_secret, scopes))
+    }
+
     /// Returns a new client with a raw access token attached to all requests.
     ///
     /// This sets `Authorization: <token>` (without `Bearer ` prefix).
@@ -134,6 +515,69 @@ impl BlockingIriClient {
         self
     }
 
+    /// Returns a new client with a raw access token that is known to expire after `lifetime`.
+    ///
+    /// See [`BlockingApiClient::with_authorization_token_expiring_in`].
+    #[must_use]
+    pub fn with_authorization_token_expiring_in(mut self, token: impl Into<String>, lifetime: Duration) -> Self {
+        self.inner = self.inner.with_authorization_token_expiring_in(token, lifetime);
+        self
+    }
+
+    /// Returns a new client that authenticates via the OAuth2 client-credentials grant.
+    ///
+    /// See [`BlockingApiClient::with_oauth2_client_credentials`].
+    #[must_use]
+    pub fn with_oauth2_client_credentials(
+        mut self,
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .with_oauth2_client_credentials(token_url, client_id, client_secret, scopes);
+        self
+    }
+
+    /// Returns a new client backed by a caller-supplied [`reqwest::blocking::Client`].
+    ///
+    /// See [`BlockingApiClient::with_http_client`].
+    #[must_use]
+    pub fn with_http_client(mut self, http: reqwest::blocking::Client) -> Self {
+        self.inner = self.inner.with_http_client(http);
+        self
+    }
+
+    /// Returns a new client with the given per-request timeout.
+    ///
+    /// See [`BlockingApiClient::with_timeout`].
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.with_timeout(timeout);
+        self
+    }
+
+    /// Returns a new client that rate-limits `call_operation`/`call_operation_as`.
+    ///
+    /// See [`IriClient::with_rate_limit`] for details.
+    #[must_use]
+    pub fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limiter = Some(Arc::new(rate_limit::build_limiter(limit)));
+        self
+    }
+
+    /// Returns a new client that retries `call_operation`/`call_operation_as` on
+    /// transient failures under `policy`.
+    ///
+    /// See [`IriClient::with_retry`] for details.
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.inner = self.inner.with_retry(policy);
+        self
+    }
+
     /// Returns all operations discovered from the `OpenAPI` spec.
     pub fn operations() -> &'static [OperationDefinition] {
         OPENAPI_OPERATIONS
@@ -157,7 +601,8 @@ impl BlockingIriClient {
     ///
     /// `path_params` replaces `{param}` segments in the operation path template.
     /// Missing required parameters return
-    /// [`ClientError::MissingPathParameter`].
+    /// [`ClientError::MissingPathParameter`]. Blocks for a permit from the
+    /// configured rate limiter, if any, before dispatching.
     pub fn call_operation(
         &self,
         operation_id: &str,
@@ -168,9 +613,176 @@ impl BlockingIriClient {
         let operation = find_operation(operation_id)?;
         let rendered_path = render_path(operation, path_params)?;
         let method = parse_method(operation)?;
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limit::wait_blocking(rate_limiter);
+        }
         self.inner
             .request_json_with_query(method, &rendered_path, query, body)
     }
+
+    /// Calls an endpoint by `OpenAPI` `operation_id` and deserializes the response into `T`.
+    ///
+    /// An empty successful body deserializes to `T::default()`. Blocks for a
+    /// permit from the configured rate limiter, if any, before dispatching.
+    pub fn call_operation_as<T: DeserializeOwned + Default>(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<T, ClientError> {
+        let operation = find_operation(operation_id)?;
+        let rendered_path = render_path(operation, path_params)?;
+        let method = parse_method(operation)?;
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limit::wait_blocking(rate_limiter);
+        }
+        self.inner
+            .request_as_with_query(method, &rendered_path, query, body)
+    }
+
+    /// Calls an endpoint by `OpenAPI` `operation_id` and deserializes the response into `T`.
+    ///
+    /// Returns `None` for a successful response with an empty body, distinguishing
+    /// "no content" from a parsed value without requiring `T: Default`. Blocks for a
+    /// permit from the configured rate limiter, if any, before dispatching.
+    pub fn call_operation_as_optional<T: DeserializeOwned>(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<T>, ClientError> {
+        let operation = find_operation(operation_id)?;
+        let rendered_path = render_path(operation, path_params)?;
+        let method = parse_method(operation)?;
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limit::wait_blocking(rate_limiter);
+        }
+        self.inner
+            .request_as_optional_with_query(method, &rendered_path, query, body)
+    }
+
+    /// Scopes `call_operation`/`call_operation_as` to operations carrying `tag`.
+    ///
+    /// See [`IriClient::tag`] for details.
+    pub fn tag<'a>(&'a self, tag: &'a str) -> BlockingIriClientTag<'a> {
+        BlockingIriClientTag { client: self, tag }
+    }
+
+    /// Returns the operations tagged `tag`, in catalog order.
+    ///
+    /// See [`IriClient::operations_by_tag`].
+    pub fn operations_by_tag(tag: &str) -> impl Iterator<Item = &'static OperationDefinition> + '_ {
+        OPENAPI_OPERATIONS
+            .iter()
+            .filter(move |op| op.tags.contains(&tag))
+    }
+
+    /// Sends a `GET` request and buffers the raw response body without JSON parsing.
+    ///
+    /// See [`ApiClient::get_bytes`].
+    pub fn get_bytes(&self, path: &str) -> Result<bytes::Bytes, ClientError> {
+        self.inner.get_bytes(path)
+    }
+
+    /// Pages through a `limit`/`offset` operation, yielding each item across pages.
+    ///
+    /// See [`IriClient::paginate`] for the async variant and stopping condition.
+    pub fn paginate<'a>(
+        &'a self,
+        operation_id: impl Into<String>,
+        path_params: &[(&str, &str)],
+        base_query: &[(&str, &str)],
+        page_size: u32,
+    ) -> PaginationIter<'a> {
+        self.paginate_with_config(
+            operation_id,
+            path_params,
+            base_query,
+            page_size,
+            PaginationConfig::default(),
+        )
+    }
+
+    /// Like [`Self::paginate`], with a custom [`PaginationConfig`].
+    pub fn paginate_with_config<'a>(
+        &'a self,
+        operation_id: impl Into<String>,
+        path_params: &[(&str, &str)],
+        base_query: &[(&str, &str)],
+        page_size: u32,
+        config: PaginationConfig,
+    ) -> PaginationIter<'a> {
+        PaginationIter {
+            state: BlockingPaginationState {
+                client: self,
+                operation_id: operation_id.into(),
+                path_params: owned_pairs(path_params),
+                base_query: owned_pairs(base_query),
+                page_size,
+                config,
+                offset: 0,
+                buffered: VecDeque::new(),
+                done: false,
+            },
+        }
+    }
+}
+
+/// A view of [`BlockingIriClient`] scoped to operations carrying one `OpenAPI` tag.
+///
+/// Returned by [`BlockingIriClient::tag`]. Synchronous counterpart of [`IriClientTag`].
+#[derive(Debug)]
+pub struct BlockingIriClientTag<'a> {
+    client: &'a BlockingIriClient,
+    tag: &'a str,
+}
+
+impl BlockingIriClientTag<'_> {
+    /// Calls `operation_id` if it carries this tag.
+    ///
+    /// See [`BlockingIriClient::call_operation`] for details.
+    pub fn call_operation(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Value, ClientError> {
+        find_operation_in_tag(self.tag, operation_id)?;
+        self.client.call_operation(operation_id, path_params, query, body)
+    }
+
+    /// Calls `operation_id` if it carries this tag, deserializing the response into `T`.
+    ///
+    /// See [`BlockingIriClient::call_operation_as`] for details.
+    pub fn call_operation_as<T: DeserializeOwned + Default>(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<T, ClientError> {
+        find_operation_in_tag(self.tag, operation_id)?;
+        self.client
+            .call_operation_as(operation_id, path_params, query, body)
+    }
+
+    /// Calls `operation_id` if it carries this tag, deserializing the response into `T`.
+    ///
+    /// See [`BlockingIriClient::call_operation_as_optional`] for details.
+    pub fn call_operation_as_optional<T: DeserializeOwned>(
+        &self,
+        operation_id: &str,
+        path_params: &[(&str, &str)],
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Option<T>, ClientError> {
+        find_operation_in_tag(self.tag, operation_id)?;
+        self.client
+            .call_operation_as_optional(operation_id, path_params, query, body)
+    }
 }
 
 /// Returns the default server URL from the `OpenAPI` spec.
@@ -187,6 +799,21 @@ fn find_operation(operation_id: &str) -> Result<&'static OperationDefinition, Cl
         .ok_or_else(|| ClientError::UnknownOperation(operation_id.to_owned()))
 }
 
+fn find_operation_in_tag(
+    tag: &str,
+    operation_id: &str,
+) -> Result<&'static OperationDefinition, ClientError> {
+    let operation = find_operation(operation_id)?;
+    if operation.tags.contains(&tag) {
+        Ok(operation)
+    } else {
+        Err(ClientError::OperationNotInTag {
+            operation_id: operation_id.to_owned(),
+            tag: tag.to_owned(),
+        })
+    }
+}
+
 fn parse_method(operation: &OperationDefinition) -> Result<Method, ClientError> {
     Method::from_bytes(operation.method.as_bytes())
         .map_err(|_| ClientError::UnknownOperation(operation.operation_id.to_owned()))
@@ -216,13 +843,172 @@ fn render_path(
 }
 
 fn encode_path_segment(value: &str) -> String {
-    byte_serialize(value.as_bytes()).collect()
+    utf8_percent_encode(value, PATH_SEGMENT_ENCODE_SET).to_string()
+}
+
+/// Rejects a zero `page_size`, which would never advance the offset or see a
+/// short page, looping forever re-requesting the same page.
+fn validate_page_size(page_size: u32) -> Result<(), ClientError> {
+    if page_size == 0 {
+        Err(ClientError::InvalidPageSize(page_size))
+    } else {
+        Ok(())
+    }
+}
+
+fn owned_pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+        .collect()
+}
+
+fn as_str_pairs(pairs: &[(String, String)]) -> Vec<(&str, &str)> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect()
+}
+
+/// Shared bookkeeping for [`IriClient::paginate`].
+struct PaginationState<'a> {
+    client: &'a IriClient,
+    operation_id: String,
+    path_params: Vec<(String, String)>,
+    base_query: Vec<(String, String)>,
+    page_size: u32,
+    config: PaginationConfig,
+    offset: u64,
+    buffered: VecDeque<Value>,
+    done: bool,
+}
+
+impl PaginationState<'_> {
+    fn next_page_query(&self) -> Vec<(String, String)> {
+        self.base_query
+            .iter()
+            .cloned()
+            .chain([
+                (self.config.limit_param.clone(), self.page_size.to_string()),
+                (self.config.offset_param.clone(), self.offset.to_string()),
+            ])
+            .collect()
+    }
+
+    fn absorb_page(&mut self, items: Vec<Value>) {
+        let page_len = items.len();
+        self.offset += u64::from(self.page_size);
+        self.buffered = items.into();
+        if page_len < self.page_size as usize {
+            self.done = true;
+        }
+    }
+}
+
+/// Shared bookkeeping for [`BlockingIriClient::paginate`].
+struct BlockingPaginationState<'a> {
+    client: &'a BlockingIriClient,
+    operation_id: String,
+    path_params: Vec<(String, String)>,
+    base_query: Vec<(String, String)>,
+    page_size: u32,
+    config: PaginationConfig,
+    offset: u64,
+    buffered: VecDeque<Value>,
+    done: bool,
+}
+
+impl BlockingPaginationState<'_> {
+    fn next_page_query(&self) -> Vec<(String, String)> {
+        self.base_query
+            .iter()
+            .cloned()
+            .chain([
+                (self.config.limit_param.clone(), self.page_size.to_string()),
+                (self.config.offset_param.clone(), self.offset.to_string()),
+            ])
+            .collect()
+    }
+
+    fn absorb_page(&mut self, items: Vec<Value>) {
+        let page_len = items.len();
+        self.offset += u64::from(self.page_size);
+        self.buffered = items.into();
+        if page_len < self.page_size as usize {
+            self.done = true;
+        }
+    }
+}
+
+/// Blocking iterator returned by [`BlockingIriClient::paginate`].
+pub struct PaginationIter<'a> {
+    state: BlockingPaginationState<'a>,
+}
+
+impl Iterator for PaginationIter<'_> {
+    type Item = Result<Value, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.state.buffered.pop_front() {
+                return Some(Ok(item));
+            }
+            if self.state.done {
+                return None;
+            }
+            if let Err(error) = validate_page_size(self.state.page_size) {
+                self.state.done = true;
+                return Some(Err(error));
+            }
+
+            let query = self.state.next_page_query();
+            let path_param_refs = as_str_pairs(&self.state.path_params);
+            let query_refs = as_str_pairs(&query);
+
+            let page = match self.state.client.call_operation(
+                &self.state.operation_id,
+                &path_param_refs,
+                &query_refs,
+                None,
+            ) {
+                Ok(page) => page,
+                Err(error) => {
+                    self.state.done = true;
+                    return Some(Err(error));
+                }
+            };
+
+            match self.state.config.extract_items(page) {
+                Ok(items) => self.state.absorb_page(items),
+                Err(error) => {
+                    self.state.done = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{IriClient, find_operation, render_path};
-    use crate::ClientError;
+    use std::collections::VecDeque;
+
+    use serde_json::json;
+
+    use super::{
+        BlockingPaginationState, IriClient, OperationDefinition, PaginationState, find_operation,
+        find_operation_in_tag, render_path, validate_page_size,
+    };
+    use crate::pagination::PaginationConfig;
+    use crate::{BlockingIriClient, ClientError};
+
+    const ECHO_ID_OPERATION: OperationDefinition = OperationDefinition {
+        operation_id: "echoId",
+        method: "GET",
+        path_template: "/api/v1/items/{item_id}",
+        path_params: &["item_id"],
+        tags: &["items"],
+    };
 
     #[test]
     fn operation_catalog_is_non_empty() {
@@ -251,4 +1037,144 @@ mod tests {
             other => panic!("unexpected error: {other}"),
         }
     }
+
+    #[test]
+    fn render_path_encodes_slashes_in_path_params() {
+        let path = render_path(&ECHO_ID_OPERATION, &[("item_id", "a/b")]).expect("path renders");
+        assert_eq!(path, "/api/v1/items/a%2Fb");
+    }
+
+    #[test]
+    fn render_path_encodes_unicode_in_path_params() {
+        let path = render_path(&ECHO_ID_OPERATION, &[("item_id", "café")]).expect("path renders");
+        assert_eq!(path, "/api/v1/items/caf%C3%A9");
+    }
+
+    #[test]
+    fn render_path_encodes_reserved_characters_in_path_params() {
+        let path =
+            render_path(&ECHO_ID_OPERATION, &[("item_id", "a b?c#d")]).expect("path renders");
+        assert_eq!(path, "/api/v1/items/a%20b%3Fc%23d");
+    }
+
+    #[test]
+    fn operations_by_tag_only_returns_matching_operations() {
+        let tagged = IriClient::operations_by_tag("facility").collect::<Vec<_>>();
+        assert!(!tagged.is_empty());
+        assert!(tagged.iter().all(|op| op.tags.contains(&"facility")));
+    }
+
+    #[test]
+    fn find_operation_in_tag_accepts_a_matching_tag() {
+        let operation = find_operation_in_tag("facility", "getSite").expect("operation is tagged");
+        assert_eq!(operation.operation_id, "getSite");
+    }
+
+    #[test]
+    fn find_operation_in_tag_rejects_a_mismatched_tag() {
+        let error = find_operation_in_tag("users", "getSite").expect_err("tag does not match");
+        match error {
+            ClientError::OperationNotInTag { operation_id, tag } => {
+                assert_eq!(operation_id, "getSite");
+                assert_eq!(tag, "users");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn validate_page_size_rejects_zero() {
+        let error = validate_page_size(0).expect_err("zero page size should error");
+        assert!(matches!(error, ClientError::InvalidPageSize(0)));
+    }
+
+    #[test]
+    fn validate_page_size_accepts_positive_values() {
+        assert!(validate_page_size(25).is_ok());
+    }
+
+    #[test]
+    fn pagination_state_next_page_query_includes_limit_offset_and_base_query() {
+        let client = IriClient::new("https://example.com").expect("valid url");
+        let state = PaginationState {
+            client: &client,
+            operation_id: "listItems".to_owned(),
+            path_params: Vec::new(),
+            base_query: vec![("active".to_owned(), "true".to_owned())],
+            page_size: 25,
+            config: PaginationConfig::default(),
+            offset: 50,
+            buffered: VecDeque::new(),
+            done: false,
+        };
+
+        let query = state.next_page_query();
+        assert!(query.contains(&("limit".to_owned(), "25".to_owned())));
+        assert!(query.contains(&("offset".to_owned(), "50".to_owned())));
+        assert!(query.contains(&("active".to_owned(), "true".to_owned())));
+    }
+
+    #[test]
+    fn pagination_state_absorb_page_stops_on_a_short_page() {
+        let client = IriClient::new("https://example.com").expect("valid url");
+        let mut state = PaginationState {
+            client: &client,
+            operation_id: "listItems".to_owned(),
+            path_params: Vec::new(),
+            base_query: Vec::new(),
+            page_size: 10,
+            config: PaginationConfig::default(),
+            offset: 0,
+            buffered: VecDeque::new(),
+            done: false,
+        };
+
+        state.absorb_page(vec![json!(1), json!(2)]);
+
+        assert!(state.done);
+        assert_eq!(state.buffered.len(), 2);
+        assert_eq!(state.offset, 10);
+    }
+
+    #[test]
+    fn pagination_state_absorb_page_continues_on_a_full_page() {
+        let client = IriClient::new("https://example.com").expect("valid url");
+        let mut state = PaginationState {
+            client: &client,
+            operation_id: "listItems".to_owned(),
+            path_params: Vec::new(),
+            base_query: Vec::new(),
+            page_size: 2,
+            config: PaginationConfig::default(),
+            offset: 0,
+            buffered: VecDeque::new(),
+            done: false,
+        };
+
+        state.absorb_page(vec![json!(1), json!(2)]);
+
+        assert!(!state.done);
+        assert_eq!(state.offset, 2);
+    }
+
+    #[test]
+    fn blocking_pagination_state_absorb_page_stops_on_a_short_page() {
+        let client = BlockingIriClient::new("https://example.com").expect("valid url");
+        let mut state = BlockingPaginationState {
+            client: &client,
+            operation_id: "listItems".to_owned(),
+            path_params: Vec::new(),
+            base_query: Vec::new(),
+            page_size: 10,
+            config: PaginationConfig::default(),
+            offset: 0,
+            buffered: VecDeque::new(),
+            done: false,
+        };
+
+        state.absorb_page(vec![json!(1)]);
+
+        assert!(state.done);
+        assert_eq!(state.buffered.len(), 1);
+    }
 }
@@ -0,0 +1,105 @@
+use serde_json::Value;
+
+use crate::ClientError;
+
+/// Configuration for [`crate::IriClient::paginate`] (and its blocking counterpart).
+///
+/// Defaults to `"limit"`/`"offset"` query parameter names with items read from the
+/// JSON response root array. Use [`Self::with_items_key`] to adapt to wrapped
+/// envelopes such as `{ "items": [...] }`.
+#[derive(Clone, Debug)]
+pub struct PaginationConfig {
+    pub(crate) limit_param: String,
+    pub(crate) offset_param: String,
+    pub(crate) items_key: Option<String>,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            limit_param: "limit".to_owned(),
+            offset_param: "offset".to_owned(),
+            items_key: None,
+        }
+    }
+}
+
+impl PaginationConfig {
+    /// Returns the default configuration (`"limit"`/`"offset"`, root-array items).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the query parameter name used for the page size (default `"limit"`).
+    #[must_use]
+    pub fn with_limit_param(mut self, name: impl Into<String>) -> Self {
+        self.limit_param = name.into();
+        self
+    }
+
+    /// Overrides the query parameter name used for the page offset (default `"offset"`).
+    #[must_use]
+    pub fn with_offset_param(mut self, name: impl Into<String>) -> Self {
+        self.offset_param = name.into();
+        self
+    }
+
+    /// Reads items from `page[key]` instead of expecting `page` itself to be an array.
+    #[must_use]
+    pub fn with_items_key(mut self, key: impl Into<String>) -> Self {
+        self.items_key = Some(key.into());
+        self
+    }
+
+    pub(crate) fn extract_items(&self, page: Value) -> Result<Vec<Value>, ClientError> {
+        let array = match &self.items_key {
+            Some(key) => page
+                .as_object()
+                .and_then(|object| object.get(key))
+                .cloned()
+                .ok_or_else(|| {
+                    ClientError::UnexpectedPaginationShape(format!(
+                        "expected an object with an '{key}' array field"
+                    ))
+                })?,
+            None => page,
+        };
+
+        match array {
+            Value::Array(items) => Ok(items),
+            other => Err(ClientError::UnexpectedPaginationShape(format!(
+                "expected a JSON array, got: {other}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::PaginationConfig;
+
+    #[test]
+    fn extract_items_reads_root_array_by_default() {
+        let config = PaginationConfig::new();
+        let items = config.extract_items(json!(["a", "b"])).expect("array");
+        assert_eq!(items, vec![json!("a"), json!("b")]);
+    }
+
+    #[test]
+    fn extract_items_reads_configured_key() {
+        let config = PaginationConfig::new().with_items_key("items");
+        let items = config
+            .extract_items(json!({"items": ["a"], "total": 1}))
+            .expect("array");
+        assert_eq!(items, vec![json!("a")]);
+    }
+
+    #[test]
+    fn extract_items_rejects_non_array_payload() {
+        let config = PaginationConfig::new();
+        assert!(config.extract_items(json!({"items": []})).is_err());
+    }
+}
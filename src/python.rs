@@ -1,12 +1,25 @@
 use std::str::FromStr;
+use std::time::Duration;
 
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use pyo3_async_runtimes::tokio::future_into_py;
 use reqwest::Method;
 use serde_json::Value;
 
-use crate::{BlockingIriClient, IriClient};
+use crate::{BlockingIriClient, IriClient, RetryPolicy};
+
+/// Builds a [`RetryPolicy`] from the `retry_max_attempts`/`retry_base_delay_ms`
+/// constructor arguments shared by [`PyClient::new`] and [`PyAsyncClient::new`].
+fn retry_policy_from_args(
+    retry_max_attempts: Option<u32>,
+    retry_base_delay_ms: Option<u64>,
+) -> Option<RetryPolicy> {
+    let max_attempts = retry_max_attempts?;
+    let base_delay_ms = retry_base_delay_ms.unwrap_or(200);
+    Some(RetryPolicy::new(max_attempts, Duration::from_millis(base_delay_ms)))
+}
 
 /// Metadata for one generated `OpenAPI` operation.
 ///
@@ -21,6 +34,8 @@ pub struct PyOperationDefinition {
     pub path_template: String,
     /// Required path-parameter names extracted from `path_template`.
     pub path_params: Vec<String>,
+    /// `OpenAPI` tags this operation belongs to, in spec order.
+    pub tags: Vec<String>,
 }
 
 /// Synchronous Python client for the IRI API.
@@ -48,17 +63,46 @@ impl PyClient {
     /// Args:
     ///     `base_url`: Base API URL. If omitted, uses the default server from the `OpenAPI` spec.
     ///     `access_token`: Optional raw token sent as `Authorization: <token>`.
+    ///     `token_url`, `client_id`, `client_secret`, `scopes`: OAuth2 client-credentials
+    ///         parameters. When `token_url`, `client_id`, and `client_secret` are all set,
+    ///         the client fetches and auto-refreshes a bearer token instead of using
+    ///         `access_token`.
+    ///     `retry_max_attempts`: Enables [`RetryPolicy`] retries when set (the attempt
+    ///         count including the first try).
+    ///     `retry_base_delay_ms`: Base delay in milliseconds for the retry backoff
+    ///         (default 200); only used when `retry_max_attempts` is set.
     #[new]
-    #[pyo3(signature = (base_url=None, access_token=None))]
-    fn new(base_url: Option<String>, access_token: Option<String>) -> PyResult<Self> {
+    #[pyo3(signature = (base_url=None, access_token=None, token_url=None, client_id=None, client_secret=None, scopes=None, retry_max_attempts=None, retry_base_delay_ms=None))]
+    fn new(
+        base_url: Option<String>,
+        access_token: Option<String>,
+        token_url: Option<String>,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+        scopes: Option<Vec<String>>,
+        retry_max_attempts: Option<u32>,
+        retry_base_delay_ms: Option<u64>,
+    ) -> PyResult<Self> {
         let client = match base_url {
             Some(url) => BlockingIriClient::new(url).map_err(to_py_value_error)?,
             None => BlockingIriClient::from_openapi_default_server().map_err(to_py_value_error)?,
         };
-        let client = if let Some(value) = access_token {
-            client.with_authorization_token(value)
-        } else {
-            client
+        let client = match (token_url, client_id, client_secret) {
+            (Some(token_url), Some(client_id), Some(client_secret)) => client
+                .with_oauth2_client_credentials(
+                    token_url,
+                    client_id,
+                    client_secret,
+                    scopes.unwrap_or_default(),
+                ),
+            _ => match access_token {
+                Some(value) => client.with_authorization_token(value),
+                None => client,
+            },
+        };
+        let client = match retry_policy_from_args(retry_max_attempts, retry_base_delay_ms) {
+            Some(policy) => client.with_retry(policy),
+            None => client,
         };
 
         Ok(Self { inner: client })
@@ -75,6 +119,14 @@ impl PyClient {
         self.request("GET", path, None, None)
     }
 
+    /// Perform a `GET` request and return the raw response body without JSON parsing.
+    ///
+    /// Use this for binary payloads (attachments, exports).
+    fn get_bytes<'py>(&self, py: Python<'py>, path: &str) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = self.inner.get_bytes(path).map_err(to_py_runtime_error)?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
     /// Perform a raw HTTP request by method and path.
     ///
     /// Args:
@@ -160,17 +212,46 @@ impl PyAsyncClient {
     /// Args:
     ///     `base_url`: Base API URL. If omitted, uses the default server from the `OpenAPI` spec.
     ///     `access_token`: Optional raw token sent as `Authorization: <token>`.
+    ///     `token_url`, `client_id`, `client_secret`, `scopes`: OAuth2 client-credentials
+    ///         parameters. When `token_url`, `client_id`, and `client_secret` are all set,
+    ///         the client fetches and auto-refreshes a bearer token instead of using
+    ///         `access_token`.
+    ///     `retry_max_attempts`: Enables [`RetryPolicy`] retries when set (the attempt
+    ///         count including the first try).
+    ///     `retry_base_delay_ms`: Base delay in milliseconds for the retry backoff
+    ///         (default 200); only used when `retry_max_attempts` is set.
     #[new]
-    #[pyo3(signature = (base_url=None, access_token=None))]
-    fn new(base_url: Option<String>, access_token: Option<String>) -> PyResult<Self> {
+    #[pyo3(signature = (base_url=None, access_token=None, token_url=None, client_id=None, client_secret=None, scopes=None, retry_max_attempts=None, retry_base_delay_ms=None))]
+    fn new(
+        base_url: Option<String>,
+        access_token: Option<String>,
+        token_url: Option<String>,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+        scopes: Option<Vec<String>>,
+        retry_max_attempts: Option<u32>,
+        retry_base_delay_ms: Option<u64>,
+    ) -> PyResult<Self> {
         let client = match base_url {
             Some(url) => IriClient::new(url).map_err(to_py_value_error)?,
             None => IriClient::from_openapi_default_server().map_err(to_py_value_error)?,
         };
-        let client = if let Some(value) = access_token {
-            client.with_authorization_token(value)
-        } else {
-            client
+        let client = match (token_url, client_id, client_secret) {
+            (Some(token_url), Some(client_id), Some(client_secret)) => client
+                .with_oauth2_client_credentials(
+                    token_url,
+                    client_id,
+                    client_secret,
+                    scopes.unwrap_or_default(),
+                ),
+            _ => match access_token {
+                Some(value) => client.with_authorization_token(value),
+                None => client,
+            },
+        };
+        let client = match retry_policy_from_args(retry_max_attempts, retry_base_delay_ms) {
+            Some(policy) => client.with_retry(policy),
+            None => client,
         };
 
         Ok(Self { inner: client })
@@ -187,6 +268,18 @@ impl PyAsyncClient {
         self.request(py, "GET", path, None, None)
     }
 
+    /// Perform an asynchronous `GET` request and return the raw response body
+    /// without JSON parsing.
+    ///
+    /// Use this for binary payloads (attachments, exports).
+    fn get_bytes<'py>(&self, py: Python<'py>, path: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.inner.clone();
+        future_into_py(py, async move {
+            let bytes = client.get_bytes(&path).await.map_err(to_py_runtime_error)?;
+            Python::with_gil(|py| Ok(PyBytes::new(py, &bytes).unbind()))
+        })
+    }
+
     /// Perform an asynchronous raw HTTP request by method and path.
     ///
     /// Args:
@@ -296,6 +389,7 @@ fn operations_for_python() -> Vec<PyOperationDefinition> {
                 .iter()
                 .map(|value| (*value).to_owned())
                 .collect(),
+            tags: op.tags.iter().map(|value| (*value).to_owned()).collect(),
         })
         .collect()
 }
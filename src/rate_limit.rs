@@ -0,0 +1,83 @@
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+use governor::clock::Clock;
+use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
+
+/// A requests-per-interval quota for [`crate::IriClient::with_rate_limit`] (and
+/// its blocking counterpart).
+///
+/// Defaults to no burst allowance beyond `requests`; see [`Self::with_burst`]
+/// to allow short spikes above the steady-state rate.
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimit {
+    requests: NonZeroU32,
+    interval: Duration,
+    burst: Option<NonZeroU32>,
+}
+
+impl RateLimit {
+    /// Allows at most `requests` operations per `interval`, evenly spaced.
+    #[must_use]
+    pub fn per_interval(requests: NonZeroU32, interval: Duration) -> Self {
+        Self {
+            requests,
+            interval,
+            burst: None,
+        }
+    }
+
+    /// Allows up to `burst` operations to fire immediately before steady-state
+    /// spacing kicks in, instead of the default (equal to `requests`).
+    #[must_use]
+    pub fn with_burst(mut self, burst: NonZeroU32) -> Self {
+        self.burst = Some(burst);
+        self
+    }
+
+    fn quota(self) -> Quota {
+        let period = self
+            .interval
+            .checked_div(self.requests.get())
+            .filter(|period| !period.is_zero())
+            .unwrap_or(self.interval);
+        Quota::with_period(period)
+            .unwrap_or_else(|| Quota::per_second(self.requests))
+            .allow_burst(self.burst.unwrap_or(self.requests))
+    }
+}
+
+pub(crate) fn build_limiter(limit: RateLimit) -> DefaultDirectRateLimiter {
+    RateLimiter::direct(limit.quota())
+}
+
+/// Blocks the current thread until `limiter` grants a permit.
+pub(crate) fn wait_blocking(limiter: &DefaultDirectRateLimiter) {
+    loop {
+        match limiter.check() {
+            Ok(()) => return,
+            Err(not_until) => std::thread::sleep(not_until.wait_time_from(governor::clock::QuantaClock::default().now())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+    use std::time::Duration;
+
+    use super::RateLimit;
+
+    #[test]
+    fn quota_defaults_burst_to_request_count() {
+        let limit = RateLimit::per_interval(NonZeroU32::new(5).unwrap(), Duration::from_secs(1));
+        assert_eq!(limit.quota().burst_size().get(), 5);
+    }
+
+    #[test]
+    fn quota_honors_explicit_burst() {
+        let limit = RateLimit::per_interval(NonZeroU32::new(5).unwrap(), Duration::from_secs(1))
+            .with_burst(NonZeroU32::new(10).unwrap());
+        assert_eq!(limit.quota().burst_size().get(), 10);
+    }
+}
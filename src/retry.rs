@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+
+/// Default cap on the computed (pre-jitter) backoff delay.
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Retry behavior for transient failures in [`crate::ApiClient`] and
+/// [`crate::BlockingApiClient`] (and, via `call_operation`, [`crate::IriClient`]
+/// and [`crate::BlockingIriClient`]).
+///
+/// Configured via `with_retry`. Only GET/HEAD/PUT/DELETE are retried by
+/// default; set `retry_post` to also retry POST requests (only safe for
+/// idempotent operations).
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first try.
+    pub max_attempts: u32,
+    /// Base delay used by the exponential backoff before jitter is applied.
+    pub base_delay: Duration,
+    /// Upper bound on the backoff delay before jitter is applied.
+    pub max_delay: Duration,
+    /// Whether `POST` requests are retried in addition to GET/HEAD/PUT/DELETE.
+    pub retry_post: bool,
+}
+
+impl RetryPolicy {
+    /// Creates a policy with the given attempt count and base delay, capping
+    /// backoff at [`DEFAULT_MAX_DELAY`] (30s) and leaving `POST` un-retried.
+    #[must_use]
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            max_delay: DEFAULT_MAX_DELAY,
+            retry_post: false,
+        }
+    }
+
+    /// Overrides the cap on the computed backoff delay (default 30s).
+    #[must_use]
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Also retries `POST` requests; only safe for idempotent operations.
+    #[must_use]
+    pub fn with_retry_post(mut self, retry_post: bool) -> Self {
+        self.retry_post = retry_post;
+        self
+    }
+
+    pub(crate) fn is_retryable_method(&self, method: &Method) -> bool {
+        match *method {
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE => true,
+            Method::POST => self.retry_post,
+            _ => false,
+        }
+    }
+
+    /// Delay before the given retry attempt (0-indexed), using exponential
+    /// backoff with full jitter: `random(0, min(base_delay * 2^attempt, max_delay))`.
+    pub(crate) fn backoff_delay(&self, attempt: u32) -> Duration {
+        let cap_secs = self.base_delay.as_secs_f64() * 2f64.powi(attempt as i32);
+        let capped_secs = cap_secs.min(self.max_delay.as_secs_f64());
+        Duration::from_secs_f64(rand::random::<f64>() * capped_secs)
+    }
+}
+
+/// Returns true for statuses worth retrying: 429 and the common transient 5xx codes.
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Returns true for transport-layer failures worth retrying (connection and timeout errors).
+pub(crate) fn is_retryable_transport_error(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout()
+}
+
+/// Determines the delay before retrying `error`, or `None` if it isn't retryable.
+///
+/// Honors a server-provided `Retry-After` delay over the computed backoff.
+pub(crate) fn retryable_delay(
+    error: &crate::ClientError,
+    policy: &RetryPolicy,
+    attempt: u32,
+) -> Option<Duration> {
+    match error {
+        crate::ClientError::HttpStatus {
+            status,
+            retry_after,
+            ..
+        } if is_retryable_status(*status) => {
+            Some(retry_after.unwrap_or_else(|| policy.backoff_delay(attempt)))
+        }
+        crate::ClientError::Request(source) if is_retryable_transport_error(source) => {
+            Some(policy.backoff_delay(attempt))
+        }
+        _ => None,
+    }
+}
+
+/// Parses a `Retry-After` header value as either delta-seconds or an HTTP-date.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let target = httpdate::parse_http_date(value.trim()).ok()?;
+    Some(
+        target
+            .duration_since(std::time::SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RetryPolicy, is_retryable_status, parse_retry_after};
+    use reqwest::{Method, StatusCode};
+
+    #[test]
+    fn retries_idempotent_methods_by_default() {
+        let policy = RetryPolicy::new(3, std::time::Duration::from_millis(10));
+        assert!(policy.is_retryable_method(&Method::GET));
+        assert!(policy.is_retryable_method(&Method::HEAD));
+        assert!(policy.is_retryable_method(&Method::PUT));
+        assert!(policy.is_retryable_method(&Method::DELETE));
+        assert!(!policy.is_retryable_method(&Method::POST));
+    }
+
+    #[test]
+    fn retries_post_when_opted_in() {
+        let policy = RetryPolicy::new(3, std::time::Duration::from_millis(10)).with_retry_post(true);
+        assert!(policy.is_retryable_method(&Method::POST));
+    }
+
+    #[test]
+    fn backoff_delay_never_exceeds_max_delay() {
+        let policy = RetryPolicy::new(10, std::time::Duration::from_secs(1))
+            .with_max_delay(std::time::Duration::from_secs(2));
+        for attempt in 0..10 {
+            assert!(policy.backoff_delay(attempt) <= std::time::Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn retryable_statuses_match_expected_set() {
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn parses_delta_seconds_retry_after() {
+        let delay = parse_retry_after("120").expect("valid delta-seconds");
+        assert_eq!(delay, std::time::Duration::from_secs(120));
+    }
+
+    #[test]
+    fn rejects_garbage_retry_after() {
+        assert!(parse_retry_after("not-a-date").is_none());
+    }
+}